@@ -16,29 +16,40 @@ use bevy::{
 
 use crate::{
     mipmap::{MipGeneratorPipelineMeta, WORKGROUP_SIZE_PER_DIM},
-    STORAGE_TEXTURE_FORMAT, TEXTURE_FORMAT,
+    MipArrayTextureBuilder, STORAGE_TEXTURE_FORMAT, TEXTURE_FORMAT,
 };
 
 #[derive(Asset, Clone, TypePath)]
 pub struct MippedArrayTexture {
     pub label: Option<&'static str>,
     pub image: Image,
-    pub dims: u32,
+    pub dims: UVec2,
     pub array_layers: u32,
     pub srgb: bool,
 }
 
 impl MippedArrayTexture {
+    /// The dimensions (width, height) of a single layer in this array texture.
+    pub fn dims(&self) -> UVec2 {
+        self.dims
+    }
+
+    /// The number of layers in this array texture.
+    pub fn layers(&self) -> u32 {
+        self.array_layers
+    }
+
     pub fn extent(&self) -> Extent3d {
         Extent3d {
-            width: self.dims,
-            height: self.dims,
+            width: self.dims.x,
+            height: self.dims.y,
             depth_or_array_layers: self.array_layers,
         }
     }
 
+    /// The number of mip levels in this array texture. See [`MipArrayTextureBuilder::mip_count`].
     pub fn mipmap_levels(&self) -> u32 {
-        self.dims.ilog2()
+        MipArrayTextureBuilder::mip_count(self.dims.min_element())
     }
 }
 
@@ -109,7 +120,7 @@ fn create_array_texture_with_filled_mip_level_0(
     texture
 }
 
-fn create_mip_view_sizes(mip_levels: u32, dims: u32) -> Vec<u32> {
+fn create_mip_view_sizes(mip_levels: u32, dims: UVec2) -> Vec<UVec2> {
     assert!(mip_levels > 1);
 
     let mut sizes = vec![dims];
@@ -229,9 +240,10 @@ impl RenderAsset for MippedArrayTexture {
 
             // Get precomputed size
             let size = view_sizes[mip_level as usize];
-            let workgroup_count: u32 = (size + WORKGROUP_SIZE_PER_DIM - 1) / WORKGROUP_SIZE_PER_DIM;
+            let workgroup_count: UVec2 =
+                (size + WORKGROUP_SIZE_PER_DIM - 1) / WORKGROUP_SIZE_PER_DIM;
 
-            pass.dispatch_workgroups(workgroup_count, workgroup_count, self.array_layers);
+            pass.dispatch_workgroups(workgroup_count.x, workgroup_count.y, self.array_layers);
         }
 
         // wgpu automatically ends the compute pass when dropping it.
@@ -278,7 +290,7 @@ impl RenderAsset for MippedArrayTexture {
                 anisotropy_clamp: 1,
                 border_color: None,
             }),
-            size: UVec2::splat(self.dims).as_vec2(),
+            size: self.dims.as_vec2(),
             mip_level_count: mip_levels,
         })
     }
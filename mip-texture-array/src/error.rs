@@ -1,4 +1,4 @@
-use bevy::prelude::*;
+use bevy::{asset::LoadDirectError, prelude::*, render::render_resource::TextureFormat};
 
 #[derive(te::Error, Debug, Clone)]
 pub enum TextureArrayBuilderError {
@@ -6,4 +6,29 @@ pub enum TextureArrayBuilderError {
     IncorrectImageDimensions { ed: u32, x: u32, y: u32 },
     #[error("Image not found in provided assets. Image handle: {0:?}")]
     ImageNotFound(AssetId<Image>),
+    #[error("Wrong image format, expected {expected:?} (or its sRGB counterpart), got {got:?}.")]
+    IncorrectImageFormat {
+        expected: TextureFormat,
+        got: TextureFormat,
+    },
+}
+
+#[derive(te::Error, Debug)]
+pub enum MatLoaderError {
+    #[error("Could not read .mat file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid .mat file, error on line {line}: {msg}")]
+    Parse { line: usize, msg: String },
+    #[error("Could not load image for .mat file: {0}")]
+    LoadDirect(Box<LoadDirectError>),
+    #[error("Asset at path '{0}' is not an image")]
+    NotAnImage(String),
+    #[error(transparent)]
+    Builder(#[from] TextureArrayBuilderError),
+}
+
+impl From<LoadDirectError> for MatLoaderError {
+    fn from(error: LoadDirectError) -> Self {
+        Self::LoadDirect(Box::new(error))
+    }
 }
@@ -12,6 +12,7 @@ use bevy::{
     },
     utils::Uuid,
 };
+use loader::MippedArrayTextureLoader;
 
 mod error;
 pub use error::*;
@@ -20,6 +21,8 @@ use crate::mipmap::{MipGeneratorPipeline, MipGeneratorPipelineMeta, MIPMAP_COMPU
 
 pub mod asset;
 
+pub mod loader;
+
 pub mod mipmap;
 
 pub const TEXTURE_FORMAT: TextureFormat = TextureFormat::Rgba8UnormSrgb;
@@ -42,6 +45,7 @@ impl Plugin for MippedArrayTexturePlugin {
         );
 
         app.init_asset::<MippedArrayTexture>();
+        app.init_asset_loader::<MippedArrayTextureLoader>();
         app.add_plugins(RenderAssetPlugin::<MippedArrayTexture>::default());
 
         let render_app = app.sub_app_mut(RenderApp);
@@ -84,6 +88,11 @@ pub struct MipArrayTextureBuilder {
 }
 
 impl MipArrayTextureBuilder {
+    /// The number of mip levels (including level 0) generated for array textures where each layer is `dims` pixels wide.
+    pub fn mip_count(dims: u32) -> u32 {
+        dims.ilog2()
+    }
+
     pub fn new(dims: u32, srgb: bool) -> Self {
         Self::new_with_format(dims, srgb, vec![0; 4], TextureFormat::Rgba8UnormSrgb)
     }
@@ -118,16 +127,9 @@ impl MipArrayTextureBuilder {
     ) -> Result<usize, TextureArrayBuilderError> {
         let image = images
             .get(asset_id)
-            .ok_or_else(|| TextureArrayBuilderError::ImageNotFound(asset_id.clone()))?;
-        let extent = image.texture_descriptor.size;
+            .ok_or(TextureArrayBuilderError::ImageNotFound(asset_id))?;
 
-        if extent.width != self.dims || extent.height != self.dims {
-            return Err(TextureArrayBuilderError::IncorrectImageDimensions {
-                ed: self.dims,
-                x: extent.width,
-                y: extent.height,
-            });
-        }
+        self.check_image(image)?;
 
         let idx = self.handles.len();
         self.handles.push(asset_id);
@@ -139,7 +141,34 @@ impl MipArrayTextureBuilder {
         images: &Assets<Image>,
         array_textures: &mut Assets<MippedArrayTexture>,
     ) -> Result<Handle<MippedArrayTexture>, TextureArrayBuilderError> {
-        let total_imgs = self.handles.len();
+        // The image might have been removed from the assets by the time that finish() is run, so we handle the error again here so we avoid panicking in a library.
+        let sources = self
+            .handles
+            .iter()
+            .map(|&asset_id| {
+                images
+                    .get(asset_id)
+                    .ok_or(TextureArrayBuilderError::ImageNotFound(asset_id))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let asset = self.build_from_images(&sources)?;
+
+        let manual_id = AssetId::Uuid {
+            uuid: Uuid::new_v4(),
+        };
+        array_textures.insert(manual_id, asset);
+
+        Ok(Handle::Weak(manual_id))
+    }
+
+    /// Build the array texture from the given images, ignoring any images added to the builder with [`MipArrayTextureBuilder::add_image`].
+    /// The images will be placed in the array texture in the order that they're provided.
+    pub(crate) fn build_from_images(
+        &self,
+        sources: &[&Image],
+    ) -> Result<MippedArrayTexture, TextureArrayBuilderError> {
+        let total_imgs = sources.len();
 
         let arr_tex_dims = Extent3d {
             width: self.dims,
@@ -155,31 +184,42 @@ impl MipArrayTextureBuilder {
             RenderAssetUsages::all(),
         );
 
-        for (idx, asset_id) in self.handles.iter().enumerate() {
-            // The image might have been removed from the assets by the time that finish() is run, so we handle the error again here so we avoid panicking in a library.
-            let source = images
-                .get(*asset_id)
-                .ok_or_else(|| TextureArrayBuilderError::ImageNotFound(asset_id.clone()))?;
-
+        for (idx, source) in sources.iter().enumerate() {
             // Finally we perform the actual copy.
             self.copy_to_arr_tex(&mut arr_texture, source, idx as _)?;
         }
 
         arr_texture.reinterpret_stacked_2d_as_array(total_imgs as _);
-        let asset = MippedArrayTexture {
+        Ok(MippedArrayTexture {
             label: self.label,
             image: arr_texture,
             array_layers: total_imgs as _,
-            dims: self.dims,
+            dims: UVec2::splat(self.dims),
             srgb: self.srgb,
-        };
+        })
+    }
 
-        let manual_id = AssetId::Uuid {
-            uuid: Uuid::new_v4(),
-        };
-        array_textures.insert(manual_id, asset);
+    /// Check that the image can be copied into the array texture, i.e., that it has the dimensions of the builder and
+    /// the same pixel layout as the array texture. Whether the image is sRGB or not doesn't matter when copying.
+    fn check_image(&self, image: &Image) -> Result<(), TextureArrayBuilderError> {
+        let extent = image.texture_descriptor.size;
+        if extent.width != self.dims || extent.height != self.dims {
+            return Err(TextureArrayBuilderError::IncorrectImageDimensions {
+                ed: self.dims,
+                x: extent.width,
+                y: extent.height,
+            });
+        }
 
-        Ok(Handle::Weak(manual_id))
+        let format = image.texture_descriptor.format;
+        if format.remove_srgb_suffix() != self.format.remove_srgb_suffix() {
+            return Err(TextureArrayBuilderError::IncorrectImageFormat {
+                expected: self.format,
+                got: format,
+            });
+        }
+
+        Ok(())
     }
 
     fn copy_to_arr_tex(
@@ -188,14 +228,7 @@ impl MipArrayTextureBuilder {
         source: &Image,
         idx: u32,
     ) -> Result<(), TextureArrayBuilderError> {
-        let extent = source.texture_descriptor.size;
-        if extent.width != self.dims || extent.height != self.dims {
-            return Err(TextureArrayBuilderError::IncorrectImageDimensions {
-                ed: self.dims,
-                x: extent.width,
-                y: extent.height,
-            });
-        }
+        self.check_image(source)?;
 
         // This code is largely taken from the crate "bevy_tile_atlas" by https://github.com/MrGVSV
         // link to function in repo: https://github.com/MrGVSV/bevy_tile_atlas/blob/main/src/tile_atlas.rs#L289
@@ -220,3 +253,52 @@ impl MipArrayTextureBuilder {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image(dims: u32, pixel: &[u8], format: TextureFormat) -> Image {
+        Image::new_fill(
+            Extent3d {
+                width: dims,
+                height: dims,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            pixel,
+            format,
+            RenderAssetUsages::all(),
+        )
+    }
+
+    #[test]
+    fn build_from_images() {
+        let builder = MipArrayTextureBuilder::new(4, false);
+        let srgb = image(4, &[1, 2, 3, 4], TextureFormat::Rgba8UnormSrgb);
+        let linear = image(4, &[5, 6, 7, 8], TextureFormat::Rgba8Unorm);
+
+        let texture = builder.build_from_images(&[&srgb, &linear]).unwrap();
+
+        assert_eq!(2, texture.array_layers);
+        assert_eq!(&srgb.data[..], &texture.image.data[..srgb.data.len()]);
+        assert_eq!(&linear.data[..], &texture.image.data[srgb.data.len()..]);
+    }
+
+    #[test]
+    fn build_from_images_errors() {
+        let builder = MipArrayTextureBuilder::new(4, true);
+
+        let grayscale = image(4, &[1], TextureFormat::R8Unorm);
+        assert!(matches!(
+            builder.build_from_images(&[&grayscale]),
+            Err(TextureArrayBuilderError::IncorrectImageFormat { .. })
+        ));
+
+        let large = image(8, &[1, 2, 3, 4], TextureFormat::Rgba8UnormSrgb);
+        assert!(matches!(
+            builder.build_from_images(&[&large]),
+            Err(TextureArrayBuilderError::IncorrectImageDimensions { .. })
+        ));
+    }
+}
@@ -0,0 +1,195 @@
+//! Asset loader for `.mat` (multi-atlas) files.
+//!
+//! A `.mat` file is a plain text file describing an array texture. Each non-empty line that isn't a comment
+//! (comments start with `#`) is either a `key = value` property or a path to an image that should be added to the
+//! array texture. Image paths are relative to the directory of the `.mat` file and are added to the array texture in
+//! the order they appear in the file. At least one image is required, and `dims` must be a power of two that's at
+//! least 4. Example:
+//!
+//! ```text
+//! # dimensions of each image (all images must be square)
+//! dims = 16
+//! # optional, defaults to true
+//! srgb = true
+//!
+//! first.png
+//! second.png
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use bevy::{
+    asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext},
+    prelude::*,
+    utils::BoxedFuture,
+};
+
+use crate::{asset::MippedArrayTexture, MatLoaderError, MipArrayTextureBuilder};
+
+/// The parsed contents of a `.mat` file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MatFile {
+    pub dims: u32,
+    pub srgb: bool,
+    pub images: Vec<PathBuf>,
+}
+
+impl MatFile {
+    /// Parse the contents of a `.mat` file.
+    pub fn parse(source: &str) -> Result<Self, MatLoaderError> {
+        let mut dims = None;
+        let mut srgb = true;
+        let mut images = Vec::new();
+
+        for (idx, line) in source.lines().enumerate() {
+            let line_num = idx + 1;
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                images.push(PathBuf::from(line));
+                continue;
+            };
+
+            let parse_error = |msg: String| MatLoaderError::Parse {
+                line: line_num,
+                msg,
+            };
+
+            let value = value.trim();
+            match key.trim() {
+                "dims" => {
+                    let parsed = value
+                        .parse::<u32>()
+                        .map_err(|_| parse_error(format!("invalid dimensions '{value}'")))?;
+
+                    // Smaller textures don't have enough mip levels for the mip generator.
+                    if parsed < 4 || !parsed.is_power_of_two() {
+                        return Err(parse_error(format!(
+                            "dimensions must be a power of two that's at least 4, got {parsed}"
+                        )));
+                    }
+
+                    dims = Some(parsed);
+                }
+                "srgb" => {
+                    srgb = value
+                        .parse::<bool>()
+                        .map_err(|_| parse_error(format!("invalid boolean '{value}'")))?;
+                }
+                unknown => return Err(parse_error(format!("unknown property '{unknown}'"))),
+            }
+        }
+
+        let Some(dims) = dims else {
+            return Err(MatLoaderError::Parse {
+                line: 0,
+                msg: "missing required property 'dims'".into(),
+            });
+        };
+
+        if images.is_empty() {
+            return Err(MatLoaderError::Parse {
+                line: 0,
+                msg: "no images listed".into(),
+            });
+        }
+
+        Ok(Self { dims, srgb, images })
+    }
+}
+
+/// Loads [`MippedArrayTexture`]s from `.mat` files. See the module level documentation for the format.
+#[derive(Default)]
+pub struct MippedArrayTextureLoader;
+
+impl AssetLoader for MippedArrayTextureLoader {
+    type Asset = MippedArrayTexture;
+    type Settings = ();
+    type Error = MatLoaderError;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a Self::Settings,
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            let mut source = String::new();
+            reader.read_to_string(&mut source).await?;
+
+            let mat = MatFile::parse(&source)?;
+            let directory = load_context
+                .path()
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_default();
+
+            let mut images = Vec::with_capacity(mat.images.len());
+            for image_path in mat.images.iter() {
+                let path = directory.join(image_path);
+                let image = load_context
+                    .load_direct(path.clone())
+                    .await?
+                    .take::<Image>()
+                    .ok_or_else(|| MatLoaderError::NotAnImage(path.display().to_string()))?;
+
+                images.push(image);
+            }
+
+            let builder = MipArrayTextureBuilder::new(mat.dims, mat.srgb);
+            let texture = builder.build_from_images(&images.iter().collect::<Vec<_>>())?;
+
+            Ok(texture)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["mat"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_mat_file() {
+        let source = "
+            # comment
+            dims = 16
+            srgb = false
+
+            first.png
+            textures/second.png
+        ";
+
+        let mat = MatFile::parse(source).unwrap();
+
+        assert_eq!(16, mat.dims);
+        assert!(!mat.srgb);
+        assert_eq!(
+            vec![
+                PathBuf::from("first.png"),
+                PathBuf::from("textures/second.png")
+            ],
+            mat.images
+        );
+    }
+
+    #[test]
+    fn parse_mat_file_errors() {
+        assert!(MatFile::parse("first.png").is_err());
+        assert!(MatFile::parse("dims = 0\nfirst.png").is_err());
+        assert!(MatFile::parse("dims = 2\nfirst.png").is_err());
+        assert!(MatFile::parse("dims = 12\nfirst.png").is_err());
+        assert!(MatFile::parse("dims = 16").is_err());
+        assert!(MatFile::parse("dims = 16\n# comment\n").is_err());
+        assert!(MatFile::parse("dims = 16\nfoo = bar").is_err());
+        assert!(MatFile::parse("dims = 16\nsrgb = maybe").is_err());
+        assert!(MatFile::parse("dims = 4\nfirst.png").is_ok());
+    }
+}
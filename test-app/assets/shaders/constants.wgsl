@@ -12,6 +12,9 @@ const FACE_SHIFT: u32 = #{FACE_SHIFT}u;
 const FLIP_UV_X_BIT: u32 = #{FLIP_UV_X_BIT}u;
 const FLIP_UV_Y_BIT: u32 = #{FLIP_UV_Y_BIT}u;
 
+const LIGHT_MASK: u32 = #{LIGHT_MASK}u;
+const LIGHT_SHIFT: u32 = #{LIGHT_SHIFT}u;
+
 const HAS_NORMAL_MAP_BIT: u32 = #{HAS_NORMAL_MAP_BIT}u;
 
 const DEFAULT_PBR_INPUT_FLAGS: u32 = #{DEFAULT_PBR_INPUT_FLAGS}u;
@@ -134,6 +134,9 @@ fn create_pbr_input(
         mip_level
     );
 
+    // the tint is packed as RGBA with red in the least significant byte
+    pbr_input.material.base_color *= unpack4x8unorm(quad.tint);
+
     pbr_input.diffuse_occlusion = vec3(1.0);
 
     if (face_texture.flags & HAS_NORMAL_MAP_BIT) != 0u {
@@ -10,6 +10,7 @@ struct ChunkQuad {
     min: vec2<f32>,
     max: vec2<f32>,
     magnitude: i32,
+    tint: u32,
 }
 
 struct ChunkQuadBitfields {
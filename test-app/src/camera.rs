@@ -4,10 +4,37 @@ use bevy::input::mouse::MouseMotion;
 use bevy::prelude::*;
 
 use bevy::window::CursorGrabMode;
+use ve::data::registries::block::BlockVariantRegistry;
+use ve::data::registries::{Registries, Registry};
+use ve::data::tile::Transparency;
+use ve::topo::access::ReadAccess;
+use ve::topo::world::{CaoBlock, VoxelRealm};
+use ve::util::ws_to_chunk_pos;
 
-#[derive(Component, Default, Copy, Clone)]
+use crate::debug_info::ShowChunkBorders;
+
+#[derive(Component, Copy, Clone)]
 pub struct PlayerCamController {
     pub controlled: bool,
+    /// How fast the camera speeds up when moving, in blocks per second squared.
+    pub acceleration: f32,
+    /// How fast the camera's velocity decays. The top speed of the camera is `acceleration / damping`.
+    pub damping: f32,
+    /// If false, the camera can't move into opaque blocks.
+    pub noclip: bool,
+    pub velocity: Vec3,
+}
+
+impl Default for PlayerCamController {
+    fn default() -> Self {
+        Self {
+            controlled: false,
+            acceleration: 250.0,
+            damping: 10.0,
+            noclip: true,
+            velocity: Vec3::ZERO,
+        }
+    }
 }
 
 #[derive(Component, Default, Copy, Clone)]
@@ -68,34 +95,94 @@ pub(crate) fn mouse_controls(
 
 pub fn kb_controls(
     input: Res<ButtonInput<KeyCode>>,
-    mut q: Query<(&mut Transform, &PlayerCamController)>,
+    mut q: Query<(&mut Transform, &mut PlayerCamController)>,
+    mut show_chunk_borders: ResMut<ShowChunkBorders>,
     t: Res<Time>,
 ) {
-    const BASE_MOVEMENT: f32 = 25.0;
+    if input.just_pressed(KeyCode::F3) {
+        show_chunk_borders.0 = !show_chunk_borders.0;
+    }
 
-    let (mut tfm, controller) = q.single_mut();
+    let (mut tfm, mut controller) = q.single_mut();
 
-    if !controller.controlled {
-        return;
+    if input.just_pressed(KeyCode::KeyN) {
+        controller.noclip = !controller.noclip;
+        info!("Noclip: {}", controller.noclip);
     }
 
-    let fwd = tfm.forward();
-    let right = tfm.right();
+    let fwd = *tfm.forward();
+    let right = *tfm.right();
 
-    let travel = t.delta_seconds() * BASE_MOVEMENT;
+    let mut direction = Vec3::ZERO;
 
-    for code in input.get_pressed() {
-        match code {
-            KeyCode::KeyW => tfm.translation += fwd * travel,
-            KeyCode::KeyA => tfm.translation -= right * travel,
-            KeyCode::KeyS => tfm.translation -= fwd * travel,
-            KeyCode::KeyD => tfm.translation += right * travel,
-            KeyCode::Space => tfm.translation.y += travel,
-            KeyCode::ShiftLeft | KeyCode::ShiftRight => tfm.translation.y -= travel,
+    if controller.controlled {
+        for code in input.get_pressed() {
+            match code {
+                KeyCode::KeyW => direction += fwd,
+                KeyCode::KeyA => direction -= right,
+                KeyCode::KeyS => direction -= fwd,
+                KeyCode::KeyD => direction += right,
+                KeyCode::Space => direction.y += 1.0,
+                KeyCode::ShiftLeft | KeyCode::ShiftRight => direction.y -= 1.0,
 
-            _ => (),
+                _ => (),
+            }
         }
     }
+
+    let dt = t.delta_seconds();
+    let decay = (-controller.damping * dt).exp();
+    controller.velocity =
+        controller.velocity * decay + direction.normalize_or_zero() * controller.acceleration * dt;
+
+    // Without noclip the camera is moved by camera_collision instead, since that needs the chunk manager.
+    if controller.noclip {
+        tfm.translation += controller.velocity * dt;
+    }
+}
+
+fn is_opaque(realm: &VoxelRealm, varreg: &BlockVariantRegistry, pos: Vec3) -> bool {
+    let ws_pos = pos.floor().as_ivec3();
+    let chunk_pos = ws_to_chunk_pos(ws_pos);
+    let ls_pos = ws_pos - chunk_pos.worldspace_min();
+
+    realm
+        .with_chunk(chunk_pos, |cref| {
+            cref.with_read_access(|access| match access.get(ls_pos) {
+                // Subdivided blocks are never treated as opaque, this collision is very rough.
+                Ok(output) => match output.block {
+                    CaoBlock::Full(block) => {
+                        varreg.get_by_id(block.id).options.transparency == Transparency::Opaque
+                    }
+                    CaoBlock::Subdivided(_) => false,
+                },
+                Err(_) => false,
+            })
+        })
+        .and_then(|opaque| opaque)
+        .unwrap_or(false)
+}
+
+pub fn camera_collision(
+    realm: VoxelRealm,
+    registries: Res<Registries>,
+    mut q: Query<(&mut Transform, &mut PlayerCamController)>,
+    t: Res<Time>,
+) {
+    let (mut tfm, mut controller) = q.single_mut();
+
+    if controller.noclip {
+        return;
+    }
+
+    let varreg = registries.get_registry::<BlockVariantRegistry>().unwrap();
+    let destination = tfm.translation + controller.velocity * t.delta_seconds();
+
+    if is_opaque(&realm, &varreg, destination) {
+        controller.velocity = Vec3::ZERO;
+    } else {
+        tfm.translation = destination;
+    }
 }
 
 pub fn cursor_grab(
@@ -3,7 +3,7 @@ use bevy::{
     prelude::*,
 };
 use ve::{
-    render::meshing::controller::ExtractableChunkMeshData,
+    render::{core::RenderCoreDebug, meshing::controller::ExtractableChunkMeshData},
     topo::{
         controller::{ChunkPermitKey, LastPosition},
         world::VoxelRealm,
@@ -13,7 +13,7 @@ use ve::{
 };
 use voxel_engine::{
     data::tile::Face,
-    topo::world::{Chunk, ChunkEntity},
+    topo::world::{Chunk, ChunkEntity, ChunkPos},
 };
 
 use crate::camera::PlayerCamController;
@@ -27,6 +27,20 @@ pub struct DirectionText;
 #[derive(Component)]
 pub struct FpsText;
 
+/// Whether to draw the borders of the chunks that observers are in.
+#[derive(Resource, Copy, Clone, Debug)]
+pub struct ShowChunkBorders(pub bool);
+
+impl Default for ShowChunkBorders {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+/// Draw a wireframe around the chunk at this entity's [`ChunkPos`].
+#[derive(Component, Default, Copy, Clone)]
+pub struct ChunkWireframe;
+
 pub fn text_section(string: impl Into<String>) -> TextSection {
     let default_style = TextStyle {
         font_size: 35.0,
@@ -42,6 +56,7 @@ pub fn update_spatial_debug_text(
     meshes: Res<ExtractableChunkMeshData>,
     mut q: Query<&mut Text, With<SpatialDebugText>>,
     player_q: Query<&Transform, With<PlayerCamController>>,
+    show_chunk_borders: Res<ShowChunkBorders>,
 ) {
     let pos = player_q.single().translation;
     let chunk_pos = ws_to_chunk_pos(pos.floor().as_ivec3());
@@ -63,6 +78,8 @@ pub fn update_spatial_debug_text(
         .ok()
         .map(|cref| cref.flags());
 
+    let loaded_chunks = realm.cm().num_loaded_chunks();
+
     let mesh = meshes.active.get(chunk_pos);
 
     for mut text in &mut q {
@@ -71,9 +88,11 @@ pub fn update_spatial_debug_text(
             format!("y: {:.5}\n", pos.y),
             format!("z: {:.5}\n", pos.z),
             format!("chunk: {}\n", chunk_pos),
+            format!("loaded chunks: {loaded_chunks}\n"),
             format!("load reasons: {load_reasons:?}\n"),
             format!("chunk flags: {chunk_flags:?}\n"),
             format!("permit flags: {permit_flags:?}\n"),
+            format!("chunk borders: {}\n", show_chunk_borders.0),
             format!("\n"),
             format!("mesh: {mesh:?}"),
         ]
@@ -117,12 +136,61 @@ pub fn update_direction_text(
     text.sections[0].value = format!("Facing: {0}", direction_letter)
 }
 
-pub fn chunk_borders(mut giz: Gizmos, observers: Query<&LastPosition, With<ChunkObserver>>) {
+fn chunk_cuboid(giz: &mut Gizmos, chunk_pos: ChunkPos, color: Color) {
+    let pos = chunk_pos.worldspace_min().as_vec3() + (Chunk::SIZE as f32 / 2.0);
+
+    let gizmo_tf = Transform::from_translation(pos).with_scale(Vec3::splat(Chunk::SIZE as _));
+    giz.cuboid(gizmo_tf, color);
+}
+
+pub fn chunk_borders(
+    mut giz: Gizmos,
+    show: Res<ShowChunkBorders>,
+    observers: Query<&LastPosition, With<ChunkObserver>>,
+) {
+    if !show.0 {
+        return;
+    }
+
     for last_pos in &observers {
-        let pos = last_pos.chunk_pos.worldspace_min().as_vec3() + (Chunk::SIZE as f32 / 2.0);
+        chunk_cuboid(&mut giz, last_pos.chunk_pos, Color::LIME_GREEN);
+    }
+}
+
+/// Chunk wireframes are drawn in debug builds, or when [`RenderCoreDebug`] is present.
+pub fn chunk_wireframes_enabled(debug: Option<Res<RenderCoreDebug>>) -> bool {
+    cfg!(debug_assertions) || debug.is_some()
+}
+
+/// Toggle the wireframe of the chunk that the player is in.
+pub fn toggle_chunk_wireframe(
+    mut cmds: Commands,
+    input: Res<ButtonInput<KeyCode>>,
+    player_q: Query<&Transform, With<PlayerCamController>>,
+    chunks: Query<(Entity, &ChunkPos, Has<ChunkWireframe>), With<ChunkEntity>>,
+) {
+    if !input.just_pressed(KeyCode::F4) {
+        return;
+    }
+
+    let player_chunk_pos = ws_to_chunk_pos(player_q.single().translation.floor().as_ivec3());
+
+    for (entity, &chunk_pos, has_wireframe) in &chunks {
+        if chunk_pos != player_chunk_pos {
+            continue;
+        }
+
+        if has_wireframe {
+            cmds.entity(entity).remove::<ChunkWireframe>();
+        } else {
+            cmds.entity(entity).insert(ChunkWireframe);
+        }
+    }
+}
 
-        let gizmo_tf = Transform::from_translation(pos).with_scale(Vec3::splat(Chunk::SIZE as _));
-        giz.cuboid(gizmo_tf, Color::LIME_GREEN);
+pub fn chunk_wireframes(mut giz: Gizmos, chunks: Query<&ChunkPos, With<ChunkWireframe>>) {
+    for &chunk_pos in &chunks {
+        chunk_cuboid(&mut giz, chunk_pos, Color::ORANGE_RED);
     }
 }
 
@@ -17,10 +17,48 @@ use bevy::prelude::*;
 use bevy::render::settings::{WgpuFeatures, WgpuSettings};
 use bevy::render::RenderPlugin;
 use bevy::window::PresentMode;
-use debug_info::{DirectionText, FpsText, SpatialDebugText};
+use debug_info::{DirectionText, FpsText, ShowChunkBorders, SpatialDebugText};
 use ve::topo::ChunkObserver;
 use ve::EngineState;
 
+#[derive(Resource, Copy, Clone, Debug)]
+struct HeadlightSettings {
+    intensity: f32,
+    range: f32,
+}
+
+impl Default for HeadlightSettings {
+    fn default() -> Self {
+        Self {
+            intensity: 10000000.0,
+            range: 10000.0,
+        }
+    }
+}
+
+impl HeadlightSettings {
+    /// Parse the headlight settings from command line arguments like `--headlight-intensity 5000`
+    /// and `--headlight-range 100`. Settings that aren't given (or can't be parsed) use their default value.
+    fn from_args(mut args: impl Iterator<Item = String>) -> Self {
+        let mut settings = Self::default();
+
+        while let Some(arg) = args.next() {
+            let setting = match arg.as_str() {
+                "--headlight-intensity" => &mut settings.intensity,
+                "--headlight-range" => &mut settings.range,
+                _ => continue,
+            };
+
+            match args.next().map(|value| value.parse::<f32>()) {
+                Some(Ok(value)) => *setting = value,
+                _ => warn!("Expected a number after '{arg}'"),
+            }
+        }
+
+        settings
+    }
+}
+
 fn main() {
     println!(
         "RUNNING IN WORKING DIRECTORY: {}",
@@ -29,6 +67,7 @@ fn main() {
 
     App::new()
         .insert_resource(ClearColor(Color::rgb(0.4, 0.75, 0.9)))
+        .init_resource::<ShowChunkBorders>()
         .add_plugins((
             DefaultPlugins
                 .set(RenderPlugin {
@@ -60,11 +99,16 @@ fn main() {
             ve::VoxelPlugin::new(vec!["test-app\\assets\\variants".into()]),
             FrameTimeDiagnosticsPlugin,
         ))
+        // Parsed after the plugins are added so that the log plugin is set up for any warnings
+        .insert_resource(HeadlightSettings::from_args(env::args().skip(1)))
         .add_systems(Startup, setup)
         .add_systems(
             Update,
             (
                 camera::kb_controls,
+                camera::camera_collision
+                    .after(camera::kb_controls)
+                    .run_if(in_state(EngineState::Finished).or_else(in_state(EngineState::Paused))),
                 camera::mouse_controls,
                 camera::cursor_grab,
             ),
@@ -74,6 +118,12 @@ fn main() {
             (
                 debug_info::update_spatial_debug_text.run_if(in_state(EngineState::Finished)),
                 debug_info::chunk_borders,
+                (
+                    debug_info::toggle_chunk_wireframe,
+                    debug_info::chunk_wireframes,
+                )
+                    .chain()
+                    .run_if(debug_info::chunk_wireframes_enabled),
                 debug_info::update_direction_text,
                 debug_info::fps_text_update_system,
             ),
@@ -83,6 +133,7 @@ fn main() {
 
 fn setup(
     _wireframe_config: ResMut<WireframeConfig>,
+    headlight: Res<HeadlightSettings>,
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
@@ -215,11 +266,11 @@ fn setup(
                 SpotLightBundle {
                     spot_light: SpotLight {
                         color: Color::WHITE,
-                        intensity: 10000000.0,
+                        intensity: headlight.intensity,
                         shadows_enabled: true,
                         inner_angle: PI / 8.0 * 0.85,
                         outer_angle: PI / 8.0,
-                        range: 10000.0,
+                        range: headlight.range,
 
                         ..default()
                     },
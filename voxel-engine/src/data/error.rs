@@ -15,7 +15,7 @@ pub enum TextureLoadingError {
 }
 
 #[derive(te::Error, Debug)]
-pub enum BlockVariantFileLoaderError {
+pub enum DescriptorFileLoaderError {
     #[error("Error walking through provided directory: {0}")]
     DirectoryWalkError(#[from] walkdir::Error),
     #[error("File at path {0} has invalid name")]
@@ -65,3 +65,11 @@ pub enum BlockModelCreationError {
     #[error("Descriptor submodel for direction {0:?} is missing face {1:?}")]
     MissingDirectionFace(Face, Face),
 }
+
+#[derive(te::Error, Clone, Debug, PartialEq)]
+pub enum DescriptorError {
+    #[error("Opaque block variants must have a model")]
+    OpaqueWithoutModel,
+    #[error("Block variants can't have both a model and a model path")]
+    ModelAndModelPath,
+}
@@ -1,9 +1,8 @@
-use std::{fs::File, io::Read, path::Path};
+use std::path::Path;
 
 use indexmap::IndexMap;
 
 use crate::data::{
-    error::BlockVariantFileLoaderError,
     resourcepath::ResourcePath,
     tile::Transparency,
     voxel::{descriptor::BlockVariantDescriptor, BlockModel},
@@ -15,7 +14,10 @@ use crate::{
     util::FaceMap,
 };
 
-use super::{error::BlockVariantRegistryLoadError, texture::TextureRegistry, Registry};
+use super::{
+    error::BlockVariantRegistryLoadError, loader::DescriptorFileLoader, model::ModelRegistry,
+    texture::TextureRegistry, Registry,
+};
 
 pub const MAX_RECURSION_DEPTH: usize = 8;
 pub static BLOCK_VARIANT_FILE_EXTENSION: &'static str = "block";
@@ -26,88 +28,15 @@ pub struct BlockVariantRegistryEntry<'a> {
     pub model: Option<&'a BlockModel>,
 }
 
-#[derive(Clone)]
-pub struct BlockVariantFileLoader {
-    raw_descriptors: hb::HashMap<ResourcePath, Vec<u8>>,
-}
-
-impl BlockVariantFileLoader {
-    pub fn new() -> Self {
-        Self {
-            raw_descriptors: hb::HashMap::new(),
-        }
-    }
-
-    pub fn labels(&self) -> impl Iterator<Item = &ResourcePath> {
-        self.raw_descriptors.keys()
-    }
-
-    pub fn entries(&self) -> impl Iterator<Item = (&ResourcePath, &[u8])> {
-        self.raw_descriptors.iter().map(|(r, v)| (r, v.as_slice()))
-    }
-
-    pub fn load_folder(
-        &mut self,
-        path: impl AsRef<Path>,
-        recurse_depth: usize,
-    ) -> Result<(), BlockVariantFileLoaderError> {
-        let path = path.as_ref();
-
-        for entry in walkdir::WalkDir::new(path).max_depth(recurse_depth) {
-            let entry = entry?;
-
-            if entry.file_type().is_file()
-                && entry
-                    .path()
-                    .extension()
-                    .is_some_and(|ext| ext == BLOCK_VARIANT_FILE_EXTENSION)
-            {
-                let rpath =
-                    ResourcePath::try_from(entry.path().strip_prefix(path).map_err(|_| {
-                        BlockVariantFileLoaderError::InvalidFileName(entry.path().to_path_buf())
-                    })?)
-                    .map_err(|_| {
-                        BlockVariantFileLoaderError::InvalidFileName(entry.path().to_path_buf())
-                    })?;
-
-                self.load_file(entry.path(), rpath)?;
-            }
-        }
-
-        Ok(())
-    }
-
-    pub fn load_file(
-        &mut self,
-        path: impl AsRef<Path>,
-        resource_path: ResourcePath,
-    ) -> Result<(), BlockVariantFileLoaderError> {
-        let path = path.as_ref();
-
-        let mut file = File::open(&path)?;
-
-        let mut buffer = Vec::<u8>::with_capacity(file.metadata()?.len() as _);
-        file.read_to_end(&mut buffer)?;
-
-        self.add_raw_buffer(resource_path, buffer);
-
-        Ok(())
-    }
-
-    pub fn add_raw_buffer(&mut self, label: ResourcePath, buffer: Vec<u8>) {
-        self.raw_descriptors.insert(label, buffer);
-    }
-}
-
 pub struct BlockVariantRegistryLoader {
-    file_loader: BlockVariantFileLoader,
+    file_loader: DescriptorFileLoader,
     manual_descriptors: hb::HashMap<ResourcePath, BlockVariantDescriptor>,
 }
 
 impl BlockVariantRegistryLoader {
     pub fn new() -> Self {
         Self {
-            file_loader: BlockVariantFileLoader::new(),
+            file_loader: DescriptorFileLoader::new(BLOCK_VARIANT_FILE_EXTENSION),
             manual_descriptors: hb::HashMap::new(),
         }
     }
@@ -129,25 +58,28 @@ impl BlockVariantRegistryLoader {
     pub fn build_registry(
         self,
         texture_registry: &TextureRegistry,
+        model_registry: &ModelRegistry,
     ) -> Result<BlockVariantRegistry, BlockVariantRegistryLoadError> {
-        let mut map =
-            IndexMap::<ResourcePath, BlockVariant, ahash::RandomState>::with_capacity_and_hasher(
-                self.manual_descriptors.len(),
-                ahash::RandomState::new(),
-            );
-
-        for (rpath, descriptor) in self.manual_descriptors.into_iter() {
-            let model = if let Some(model_desc) = descriptor.model {
-                Some(model_desc.create_block_model(texture_registry)?)
-            } else {
-                None
-            };
+        Ok(BlockVariantRegistry::from_map(
+            self.build_variants(texture_registry, model_registry)?,
+        ))
+    }
 
-            let variant = BlockVariant {
-                options: descriptor.options,
-                model,
-            };
+    /// Build the block variants registered in this loader without constructing a registry from them.
+    /// The returned variants can be merged into an existing registry with [`BlockVariantRegistry::extend`].
+    pub fn build_variants(
+        self,
+        texture_registry: &TextureRegistry,
+        model_registry: &ModelRegistry,
+    ) -> Result<BlockVariantMap, BlockVariantRegistryLoadError> {
+        let mut map = BlockVariantMap::with_capacity_and_hasher(
+            self.manual_descriptors.len(),
+            ahash::RandomState::new(),
+        );
 
+        for (rpath, descriptor) in self.manual_descriptors.into_iter() {
+            let variant =
+                Self::build_variant(&rpath, descriptor, texture_registry, model_registry)?;
             map.insert(rpath, variant);
         }
 
@@ -155,24 +87,45 @@ impl BlockVariantRegistryLoader {
             let descriptor =
                 toml::from_str::<BlockVariantDescriptor>(String::from_utf8_lossy(buffer).as_ref())?;
 
-            let model = if let Some(model_desc) = descriptor.model {
-                Some(model_desc.create_block_model(texture_registry)?)
-            } else {
-                None
-            };
-
-            let variant = BlockVariant {
-                options: descriptor.options,
-                model,
-            };
-
+            let variant = Self::build_variant(rpath, descriptor, texture_registry, model_registry)?;
             map.insert(rpath.clone(), variant);
         }
 
-        Ok(BlockVariantRegistry { map })
+        Ok(map)
+    }
+
+    fn build_variant(
+        rpath: &ResourcePath,
+        descriptor: BlockVariantDescriptor,
+        texture_registry: &TextureRegistry,
+        model_registry: &ModelRegistry,
+    ) -> Result<BlockVariant, BlockVariantRegistryLoadError> {
+        descriptor.validate().map_err(|error| {
+            BlockVariantRegistryLoadError::InvalidDescriptor(rpath.clone(), error)
+        })?;
+
+        let model = if let Some(model_desc) = descriptor.model {
+            Some(model_desc.create_block_model(texture_registry)?)
+        } else if let Some(model_path) = descriptor.model_path {
+            let model = model_registry
+                .get_by_label(&model_path)
+                .and_then(|model| model.into_block_model())
+                .ok_or(BlockVariantRegistryLoadError::ModelNotFound(model_path))?;
+
+            Some(model)
+        } else {
+            None
+        };
+
+        Ok(BlockVariant {
+            options: descriptor.options,
+            model,
+        })
     }
 }
 
+pub type BlockVariantMap = IndexMap<ResourcePath, BlockVariant, ahash::RandomState>;
+
 #[derive(PartialEq, Eq, Hash, Clone, Debug)]
 pub struct BlockVariant {
     options: BlockOptions,
@@ -206,11 +159,70 @@ impl BlockVariantId {
 }
 
 pub struct BlockVariantRegistry {
-    map: IndexMap<ResourcePath, BlockVariant, ahash::RandomState>,
+    map: BlockVariantMap,
+    transparency_index: hb::HashMap<Transparency, Vec<BlockVariantId>>,
 }
 
 impl BlockVariantRegistry {
     pub const RPATH_VOID: &'static str = "void";
+
+    fn from_map(map: BlockVariantMap) -> Self {
+        let mut registry = Self {
+            map,
+            transparency_index: hb::HashMap::new(),
+        };
+
+        registry.rebuild_transparency_index();
+        registry
+    }
+
+    fn rebuild_transparency_index(&mut self) {
+        self.transparency_index.clear();
+
+        for (idx, variant) in self.map.values().enumerate() {
+            self.transparency_index
+                .entry(variant.options.transparency)
+                .or_default()
+                .push(BlockVariantId(idx as _));
+        }
+    }
+
+    /// Add the given variants to this registry. Existing IDs are never invalidated, and existing variants can't be
+    /// replaced since chunks that were already meshed with them would be stale. If any of the labels is already
+    /// registered nothing is added and an error is returned.
+    /// Returns the IDs of the added variants.
+    pub fn extend(
+        &mut self,
+        variants: BlockVariantMap,
+    ) -> Result<Vec<BlockVariantId>, BlockVariantRegistryLoadError> {
+        if let Some(rpath) = variants.keys().find(|rpath| self.map.contains_key(*rpath)) {
+            return Err(BlockVariantRegistryLoadError::AlreadyRegistered(
+                rpath.clone(),
+            ));
+        }
+
+        let added = variants
+            .into_iter()
+            .map(|(rpath, variant)| BlockVariantId(self.map.insert_full(rpath, variant).0 as _))
+            .collect();
+
+        self.rebuild_transparency_index();
+        Ok(added)
+    }
+
+    /// Iterate over all the block variants with the given transparency. This uses an index built when the registry
+    /// is created, so it doesn't need to traverse the entire registry.
+    pub fn query_by_transparency(
+        &self,
+        transparency: Transparency,
+    ) -> impl Iterator<Item = (BlockVariantId, BlockVariantRegistryEntry<'_>)> + '_ {
+        self.transparency_index
+            .get(&transparency)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+            .iter()
+            .map(|&id| (id, self.get_by_id(id)))
+    }
 }
 
 #[cfg(test)]
@@ -264,7 +276,7 @@ impl BlockVariantRegistry {
             },
         );
 
-        Self { map }
+        Self::from_map(map)
     }
 }
 
@@ -298,5 +310,103 @@ impl Registry for BlockVariantRegistry {
 
 #[cfg(test)]
 mod tests {
-    // TODO: tests!
+    use crate::data::registries::model::ModelRegistryLoader;
+
+    use super::*;
+
+    fn transparent_descriptor() -> BlockVariantDescriptor {
+        BlockVariantDescriptor {
+            options: BlockOptions {
+                transparency: Transparency::Transparent,
+                subdividable: false,
+            },
+            model: None,
+            model_path: None,
+        }
+    }
+
+    #[test]
+    fn extend_keeps_existing_ids() {
+        let texreg = TextureRegistry::new_mock();
+        let modelreg = ModelRegistryLoader::new().build_registry(&texreg).unwrap();
+        let mut varreg = BlockVariantRegistry::new_mock(&texreg);
+
+        let mut loader = BlockVariantRegistryLoader::new();
+        loader.register(rpath("new"), transparent_descriptor());
+
+        let added = varreg
+            .extend(loader.build_variants(&texreg, &modelreg).unwrap())
+            .unwrap();
+
+        assert_eq!(vec![BlockVariantId::new(3)], added);
+        assert_eq!(
+            Some(BlockVariantRegistry::FULL),
+            varreg.get_id(&rpath(BlockVariantRegistry::RPATH_FULL))
+        );
+        assert_eq!(
+            Some(BlockVariantRegistry::SUBDIV),
+            varreg.get_id(&rpath(BlockVariantRegistry::RPATH_SUBDIV))
+        );
+        assert_eq!(Some(BlockVariantId::new(3)), varreg.get_id(&rpath("new")));
+
+        let transparent = varreg
+            .query_by_transparency(Transparency::Transparent)
+            .map(|(id, _)| id)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![BlockVariantRegistry::VOID, BlockVariantId::new(3)],
+            transparent
+        );
+    }
+
+    #[test]
+    fn extend_rejects_registered_labels() {
+        let texreg = TextureRegistry::new_mock();
+        let modelreg = ModelRegistryLoader::new().build_registry(&texreg).unwrap();
+        let mut varreg = BlockVariantRegistry::new_mock(&texreg);
+
+        let mut loader = BlockVariantRegistryLoader::new();
+        loader.register(rpath("new"), transparent_descriptor());
+        loader.register(
+            rpath(BlockVariantRegistry::RPATH_FULL),
+            transparent_descriptor(),
+        );
+
+        let result = varreg.extend(loader.build_variants(&texreg, &modelreg).unwrap());
+
+        assert!(matches!(
+            result,
+            Err(BlockVariantRegistryLoadError::AlreadyRegistered(_))
+        ));
+        assert_eq!(None, varreg.get_id(&rpath("new")));
+        assert_eq!(
+            Transparency::Opaque,
+            varreg
+                .get_by_id(BlockVariantRegistry::FULL)
+                .options
+                .transparency
+        );
+    }
+
+    #[test]
+    fn query_by_transparency() {
+        let texreg = TextureRegistry::new_mock();
+        let varreg = BlockVariantRegistry::new_mock(&texreg);
+
+        let opaque = varreg
+            .query_by_transparency(Transparency::Opaque)
+            .map(|(id, _)| id)
+            .collect::<Vec<_>>();
+        let transparent = varreg
+            .query_by_transparency(Transparency::Transparent)
+            .map(|(id, _)| id)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![BlockVariantRegistry::FULL, BlockVariantRegistry::SUBDIV],
+            opaque
+        );
+        assert_eq!(vec![BlockVariantRegistry::VOID], transparent);
+    }
 }
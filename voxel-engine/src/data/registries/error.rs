@@ -7,7 +7,7 @@ use bevy::{
 use mip_texture_array::TextureArrayBuilderError;
 
 use crate::data::{
-    error::{BlockModelCreationError, BlockVariantFileLoaderError},
+    error::{BlockModelCreationError, DescriptorError, DescriptorFileLoaderError},
     resourcepath::{error::FromPathError, ResourcePath},
     systems::{VoxelNormalMapFolder, VoxelTextureFolder},
 };
@@ -49,7 +49,23 @@ pub enum BlockVariantRegistryLoadError {
     #[error("Error creating block model: {0}")]
     ModelCreationError(#[from] BlockModelCreationError),
     #[error("Error loading block variant from file: {0}")]
-    FileLoadError(#[from] BlockVariantFileLoaderError),
+    FileLoadError(#[from] DescriptorFileLoaderError),
     #[error("Error parsing block variant TOML file: {0}")]
     TomlParseError(#[from] toml::de::Error),
+    #[error("Invalid descriptor for block variant '{0}': {1}")]
+    InvalidDescriptor(ResourcePath, DescriptorError),
+    #[error("Block model with label '{0}' not found")]
+    ModelNotFound(ResourcePath),
+    #[error("Block variant with label '{0}' is already registered")]
+    AlreadyRegistered(ResourcePath),
+}
+
+#[derive(Debug, te::Error)]
+pub enum ModelRegistryLoadError {
+    #[error("Error creating block model: {0}")]
+    ModelCreationError(#[from] BlockModelCreationError),
+    #[error("Error loading model from file: {0}")]
+    FileLoadError(#[from] DescriptorFileLoaderError),
+    #[error("Error parsing model TOML file: {0}")]
+    TomlParseError(#[from] toml::de::Error),
 }
@@ -0,0 +1,80 @@
+use std::{fs::File, io::Read, path::Path};
+
+use crate::data::{error::DescriptorFileLoaderError, resourcepath::ResourcePath};
+
+/// Loads the raw contents of descriptor files, labelled by their path relative to the folder they were loaded from.
+#[derive(Clone)]
+pub struct DescriptorFileLoader {
+    raw_descriptors: hb::HashMap<ResourcePath, Vec<u8>>,
+    extension: &'static str,
+}
+
+impl DescriptorFileLoader {
+    /// Create a loader that loads the files with the given extension when loading folders.
+    pub fn new(extension: &'static str) -> Self {
+        Self {
+            raw_descriptors: hb::HashMap::new(),
+            extension,
+        }
+    }
+
+    pub fn labels(&self) -> impl Iterator<Item = &ResourcePath> {
+        self.raw_descriptors.keys()
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = (&ResourcePath, &[u8])> {
+        self.raw_descriptors.iter().map(|(r, v)| (r, v.as_slice()))
+    }
+
+    pub fn load_folder(
+        &mut self,
+        path: impl AsRef<Path>,
+        recurse_depth: usize,
+    ) -> Result<(), DescriptorFileLoaderError> {
+        let path = path.as_ref();
+
+        for entry in walkdir::WalkDir::new(path).max_depth(recurse_depth) {
+            let entry = entry?;
+
+            if entry.file_type().is_file()
+                && entry
+                    .path()
+                    .extension()
+                    .is_some_and(|ext| ext == self.extension)
+            {
+                let rpath =
+                    ResourcePath::try_from(entry.path().strip_prefix(path).map_err(|_| {
+                        DescriptorFileLoaderError::InvalidFileName(entry.path().to_path_buf())
+                    })?)
+                    .map_err(|_| {
+                        DescriptorFileLoaderError::InvalidFileName(entry.path().to_path_buf())
+                    })?;
+
+                self.load_file(entry.path(), rpath)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn load_file(
+        &mut self,
+        path: impl AsRef<Path>,
+        resource_path: ResourcePath,
+    ) -> Result<(), DescriptorFileLoaderError> {
+        let path = path.as_ref();
+
+        let mut file = File::open(&path)?;
+
+        let mut buffer = Vec::<u8>::with_capacity(file.metadata()?.len() as _);
+        file.read_to_end(&mut buffer)?;
+
+        self.add_raw_buffer(resource_path, buffer);
+
+        Ok(())
+    }
+
+    pub fn add_raw_buffer(&mut self, label: ResourcePath, buffer: Vec<u8>) {
+        self.raw_descriptors.insert(label, buffer);
+    }
+}
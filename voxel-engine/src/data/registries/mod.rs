@@ -12,6 +12,7 @@ use super::resourcepath::ResourcePath;
 
 pub mod block;
 pub mod error;
+pub mod loader;
 pub mod model;
 pub mod texture;
 
@@ -64,4 +65,14 @@ impl Registries {
             Some(RwLockReadGuard::map(guard, |g| g.get::<R>().unwrap()))
         }
     }
+
+    /// Run `f` with mutable access to a registry, returning its result (or `None` if the registry doesn't exist).
+    /// This locks all registries while `f` runs, so other threads (like the mesh builder workers) can't read any
+    /// registry until it returns. Keep `f` short and don't access other registries in it.
+    pub fn update_registry<R: Registry + 'static, T>(
+        &self,
+        f: impl FnOnce(&mut R) -> T,
+    ) -> Option<T> {
+        self.registries.write().get_mut::<R>().map(f)
+    }
 }
@@ -1 +1,134 @@
+use std::path::Path;
 
+use indexmap::IndexMap;
+
+use crate::data::{
+    resourcepath::ResourcePath,
+    voxel::{descriptor::BlockModelDescriptor, VoxelModel},
+};
+
+use super::{
+    block::MAX_RECURSION_DEPTH, error::ModelRegistryLoadError, loader::DescriptorFileLoader,
+    texture::TextureRegistry, Registry,
+};
+
+pub static MODEL_FILE_EXTENSION: &str = "model";
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, dm::Display)]
+#[display(fmt = "[model:{:08}]", self.0)]
+pub struct ModelId(u32);
+
+impl ModelId {
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+pub struct ModelRegistryLoader {
+    file_loader: DescriptorFileLoader,
+    manual_models: hb::HashMap<ResourcePath, VoxelModel>,
+}
+
+impl Default for ModelRegistryLoader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ModelRegistryLoader {
+    pub fn new() -> Self {
+        Self {
+            file_loader: DescriptorFileLoader::new(MODEL_FILE_EXTENSION),
+            manual_models: hb::HashMap::new(),
+        }
+    }
+
+    /// Register all the model files in the given directory. Model files are block model descriptors in TOML.
+    pub fn register_from_directory(
+        &mut self,
+        path: impl AsRef<Path>,
+        recurse: bool,
+    ) -> Result<(), ModelRegistryLoadError> {
+        let depth = if recurse { MAX_RECURSION_DEPTH } else { 0 };
+
+        Ok(self.file_loader.load_folder(path, depth)?)
+    }
+
+    pub fn register(&mut self, label: ResourcePath, model: VoxelModel) {
+        self.manual_models.insert(label, model);
+    }
+
+    pub fn build_registry(
+        self,
+        texture_registry: &TextureRegistry,
+    ) -> Result<ModelRegistry, ModelRegistryLoadError> {
+        let mut map =
+            ModelMap::with_capacity_and_hasher(self.manual_models.len(), ahash::RandomState::new());
+
+        map.extend(self.manual_models);
+
+        for (rpath, buffer) in self.file_loader.entries() {
+            let descriptor =
+                toml::from_str::<BlockModelDescriptor>(String::from_utf8_lossy(buffer).as_ref())?;
+
+            let model = descriptor.create_block_model(texture_registry)?;
+            map.insert(rpath.clone(), VoxelModel::Block(model));
+        }
+
+        Ok(ModelRegistry { map })
+    }
+}
+
+pub type ModelMap = IndexMap<ResourcePath, VoxelModel, ahash::RandomState>;
+
+/// Registry of the models that block variants can refer to by path.
+pub struct ModelRegistry {
+    map: ModelMap,
+}
+
+impl Registry for ModelRegistry {
+    type Item<'a> = &'a VoxelModel;
+    type Id = ModelId;
+
+    fn get_by_label(&self, label: &ResourcePath) -> Option<Self::Item<'_>> {
+        self.map.get(label)
+    }
+
+    fn get_by_id(&self, id: Self::Id) -> Self::Item<'_> {
+        self.map.get_index(id.index()).unwrap().1
+    }
+
+    fn get_id(&self, label: &ResourcePath) -> Option<Self::Id> {
+        self.map.get_index_of(label).map(|idx| ModelId(idx as _))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::{
+        resourcepath::rpath, texture::FaceTexture, voxel::rotations::BlockModelFaceMap,
+        voxel::BlockModel,
+    };
+    use crate::util::FaceMap;
+
+    use super::*;
+
+    #[test]
+    fn register_models() {
+        let texreg = TextureRegistry::new_mock();
+        let model = VoxelModel::Block(BlockModel {
+            directions: FaceMap::new(),
+            model: BlockModelFaceMap::filled(FaceTexture::new(TextureRegistry::TEX1)),
+        });
+
+        let mut loader = ModelRegistryLoader::new();
+        loader.register(rpath("cube"), model);
+
+        let modelreg = loader.build_registry(&texreg).unwrap();
+        let id = modelreg.get_id(&rpath("cube")).unwrap();
+
+        assert_eq!(Some(&model), modelreg.get_by_label(&rpath("cube")));
+        assert_eq!(&model, modelreg.get_by_id(id));
+        assert_eq!(None, modelreg.get_id(&rpath("missing")));
+    }
+}
@@ -1,4 +1,4 @@
-use std::{path::PathBuf, sync::Arc};
+use std::{path::PathBuf, sync::Arc, time::Duration};
 
 use bevy::{
     asset::LoadedFolder,
@@ -16,8 +16,9 @@ use crate::{
 use super::{
     error::TextureAtlasesGetAssetError,
     registries::{
-        block::{BlockOptions, BlockVariantRegistry, BlockVariantRegistryLoader},
-        error::{BlockVariantRegistryLoadError, TextureRegistryError},
+        block::{BlockOptions, BlockVariantId, BlockVariantRegistry, BlockVariantRegistryLoader},
+        error::{BlockVariantRegistryLoadError, ModelRegistryLoadError, TextureRegistryError},
+        model::{ModelRegistry, ModelRegistryLoader},
         texture::{TexregFaces, TextureRegistry},
         Registries,
     },
@@ -114,6 +115,23 @@ impl<'w> ArrayTextureHandles<'w> {
 #[derive(Resource, Deref, dm::Constructor)]
 pub struct VariantFolders(Arc<Vec<PathBuf>>);
 
+impl VariantFolders {
+    /// Add a folder to the variant folders. This only records the folder, to load its variants into the registry
+    /// at runtime send a [`VariantFolderAdded`] event instead.
+    pub fn add_folder(&mut self, path: PathBuf) {
+        Arc::make_mut(&mut self.0).push(path);
+    }
+}
+
+/// Send this event to add a variant folder at runtime. The variants in the folder are loaded and added to the
+/// existing [`BlockVariantRegistry`] without invalidating existing block variant IDs.
+///
+/// Only block variants can be added at runtime, the GPU-side registries aren't rebuilt. This means the added variants
+/// can only use textures and models that are already registered (anything else is a load error), and texture or model
+/// files in the added folder are ignored. Variants that are already registered can't be replaced either.
+#[derive(Event, Clone, Debug)]
+pub struct VariantFolderAdded(pub PathBuf);
+
 pub(crate) fn load_textures(mut cmds: Commands, server: Res<AssetServer>) {
     cmds.insert_resource(VoxelTextureFolder {
         handle: server.load_folder(TEXTURE_FOLDER_NAME),
@@ -125,12 +143,69 @@ pub(crate) fn load_textures(mut cmds: Commands, server: Res<AssetServer>) {
     });
 }
 
+/// How long to wait for the voxel textures to load before giving up and transitioning to [`EngineState::Error`].
+#[derive(Resource, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TextureLoadTimeout(pub Duration);
+
+impl Default for TextureLoadTimeout {
+    fn default() -> Self {
+        Self(Duration::from_secs(30))
+    }
+}
+
+/// How many of the voxel textures and normal maps have been loaded so far. Updated every frame while the engine is
+/// being set up. The total is 0 until the asset server knows which files are in the texture folders.
+#[derive(Resource, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TextureLoadProgress {
+    pub loaded: usize,
+    pub total: usize,
+}
+
+impl TextureLoadProgress {
+    /// The loading progress as a fraction between 0 and 1.
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.loaded as f32 / self.total as f32
+        }
+    }
+}
+
+/// Sent if the voxel textures didn't load within the [`TextureLoadTimeout`].
+#[derive(Event, Copy, Clone, Debug)]
+pub struct TextureLoadTimedOut {
+    pub timeout: Duration,
+}
+
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn check_textures(
     mut next_state: ResMut<NextState<EngineState>>,
     mut texture_folder: ResMut<VoxelTextureFolder>,
     mut normalmap_folder: ResMut<VoxelNormalMapFolder>,
     mut events: EventReader<AssetEvent<LoadedFolder>>,
+    mut timed_out: EventWriter<TextureLoadTimedOut>,
+    mut elapsed: Local<Duration>,
+    timeout: Res<TextureLoadTimeout>,
+    time: Res<Time<Real>>,
+    mut progress: ResMut<TextureLoadProgress>,
+    loaded_folders: Res<Assets<LoadedFolder>>,
+    server: Res<AssetServer>,
 ) {
+    let handles = [&texture_folder.handle, &normalmap_folder.handle]
+        .into_iter()
+        .filter_map(|handle| loaded_folders.get(handle))
+        .flat_map(|folder| folder.handles.iter());
+
+    *progress = TextureLoadProgress::default();
+    for handle in handles {
+        progress.total += 1;
+
+        if server.is_loaded_with_dependencies(handle) {
+            progress.loaded += 1;
+        }
+    }
+
     for event in events.read() {
         if event.is_loaded_with_dependencies(&texture_folder.handle) {
             texture_folder.loaded = true;
@@ -143,6 +218,19 @@ pub(crate) fn check_textures(
 
     if texture_folder.loaded && normalmap_folder.loaded {
         next_state.set(EngineState::Finished);
+        return;
+    }
+
+    *elapsed += time.delta();
+
+    if *elapsed >= timeout.0 {
+        error!(
+            "Voxel textures didn't load within {} seconds",
+            timeout.0.as_secs_f32()
+        );
+
+        timed_out.send(TextureLoadTimedOut { timeout: timeout.0 });
+        next_state.set(EngineState::Error);
     }
 }
 
@@ -210,11 +298,30 @@ fn create_texture_registry(
     Ok(registry_loader.build_registry(images.as_ref(), &mut array_textures)?)
 }
 
+/// Models are loaded from the same folders as block variants, so variants can refer to models next to them.
+fn create_model_registry(
+    registries: Res<Registries>,
+    folders: Res<VariantFolders>,
+) -> Result<ModelRegistry, ModelRegistryLoadError> {
+    let texreg = registries.get_registry::<TextureRegistry>().unwrap();
+    let mut loader = ModelRegistryLoader::new();
+
+    for folder in folders.iter() {
+        if let Err(err) = loader.register_from_directory(folder, true) {
+            let path = folder.as_path().to_string_lossy();
+            error!("Error while loading models from folder at path '{path}': '{err}'");
+        }
+    }
+
+    loader.build_registry(&texreg)
+}
+
 fn create_block_variant_registry(
     registries: Res<Registries>,
     folders: Res<VariantFolders>,
 ) -> Result<BlockVariantRegistry, BlockVariantRegistryLoadError> {
     let texreg = registries.get_registry::<TextureRegistry>().unwrap();
+    let modelreg = registries.get_registry::<ModelRegistry>().unwrap();
     let mut loader = BlockVariantRegistryLoader::new();
 
     loader.register(
@@ -225,6 +332,7 @@ fn create_block_variant_registry(
                 subdividable: true,
             },
             model: None,
+            model_path: None,
         },
     );
 
@@ -235,11 +343,64 @@ fn create_block_variant_registry(
         }
     }
 
-    loader.build_registry(&texreg)
+    loader.build_registry(&texreg, &modelreg)
+}
+
+/// Build the variants in the loader and merge them into the block variant registry, returning the IDs of the new variants.
+fn extend_block_variant_registry(
+    registries: &Registries,
+    loader: BlockVariantRegistryLoader,
+) -> Result<Vec<BlockVariantId>, BlockVariantRegistryLoadError> {
+    let variants = {
+        let texreg = registries.get_registry::<TextureRegistry>().unwrap();
+        let modelreg = registries.get_registry::<ModelRegistry>().unwrap();
+        loader.build_variants(&texreg, &modelreg)?
+    };
+
+    registries
+        .update_registry::<BlockVariantRegistry, _>(|varreg| varreg.extend(variants))
+        .unwrap()
+}
+
+pub(crate) fn load_added_variant_folders(
+    mut events: EventReader<VariantFolderAdded>,
+    mut folders: ResMut<VariantFolders>,
+    registries: Option<Res<Registries>>,
+) {
+    for VariantFolderAdded(folder) in events.read() {
+        folders.add_folder(folder.clone());
+
+        // If the registries haven't been built yet the folder will be loaded along with the rest of the folders.
+        let Some(registries) = registries.as_deref() else {
+            continue;
+        };
+
+        let path = folder.as_path().to_string_lossy();
+        let mut loader = BlockVariantRegistryLoader::new();
+
+        if let Err(err) = loader.register_from_directory(folder, true) {
+            error!("Error while loading variant folder at path '{path}': '{err}'");
+            continue;
+        }
+
+        let added = match extend_block_variant_registry(registries, loader) {
+            Ok(added) => added,
+            Err(err) => {
+                error!("Error while building variants from folder at path '{path}': '{err}'");
+                continue;
+            }
+        };
+
+        info!(
+            "Loaded variant folder at path '{path}' with {} new block variants",
+            added.len()
+        );
+    }
 }
 
 pub fn build_registries(world: &mut World) {
     let create_texreg_sysid = world.register_system(create_texture_registry);
+    let create_modelreg_sysid = world.register_system(create_model_registry);
     let create_block_variant_sysid = world.register_system(create_block_variant_registry);
 
     world.insert_resource(Registries::new());
@@ -258,10 +419,20 @@ pub fn build_registries(world: &mut World) {
     world.insert_resource(VoxelColorArrayTexture(texreg.color_texture().clone()));
     world.insert_resource(VoxelNormalArrayTexture(texreg.normal_texture().clone()));
     world.insert_resource(TexregFaces(texreg.face_texture_buffer()));
-    let registries = world.resource_mut::<Registries>();
+    world.resource::<Registries>().add_registry(texreg);
+
+    let modelreg = match world
+        .run_system::<Result<ModelRegistry, ModelRegistryLoadError>>(create_modelreg_sysid)
+        .unwrap()
+    {
+        Ok(registry) => registry,
+        Err(error) => {
+            error!("Error creating model registry: {error}");
+            panic!();
+        }
+    };
 
-    registries.add_registry(texreg);
-    drop(registries);
+    world.resource::<Registries>().add_registry(modelreg);
 
     let blockreg = match world
         .run_system::<Result<BlockVariantRegistry, BlockVariantRegistryLoadError>>(
@@ -276,6 +447,88 @@ pub fn build_registries(world: &mut World) {
         }
     };
 
-    let registries = world.resource_mut::<Registries>();
-    registries.add_registry(blockreg);
+    world.resource::<Registries>().add_registry(blockreg);
+}
+
+/// Add new block variants to the existing block variant registry without rebuilding any of the registries.
+/// Existing block variant IDs stay valid, and labels that are already registered are rejected (see [`VariantFolderAdded`]
+/// for what can be added at runtime). Returns the IDs of the added variants.
+///
+/// # Panics
+/// Panics if the registries haven't been built yet.
+pub fn rebuild_registries_partial(
+    world: &mut World,
+    new_descriptors: Vec<(ResourcePath, BlockVariantDescriptor)>,
+) -> Result<Vec<BlockVariantId>, BlockVariantRegistryLoadError> {
+    let mut loader = BlockVariantRegistryLoader::new();
+
+    for (label, descriptor) in new_descriptors.into_iter() {
+        loader.register(label, descriptor);
+    }
+
+    let registries = world.resource::<Registries>();
+    extend_block_variant_registry(registries, loader)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::registries::{model::ModelRegistryLoader, Registry};
+
+    use super::*;
+
+    #[test]
+    fn rebuild_registries_partial_keeps_existing_variants() {
+        let texreg = TextureRegistry::new_mock();
+        let modelreg = ModelRegistryLoader::new().build_registry(&texreg).unwrap();
+        let varreg = BlockVariantRegistry::new_mock(&texreg);
+
+        let registries = Registries::new();
+        registries.add_registry(texreg);
+        registries.add_registry(modelreg);
+        registries.add_registry(varreg);
+
+        let existing = [
+            BlockVariantRegistry::RPATH_VOID,
+            BlockVariantRegistry::RPATH_FULL,
+            BlockVariantRegistry::RPATH_SUBDIV,
+        ]
+        .map(|label| {
+            let varreg = registries.get_registry::<BlockVariantRegistry>().unwrap();
+            let id = varreg.get_id(&rpath(label)).unwrap();
+            let entry = varreg.get_by_id(id);
+
+            (label, id, entry.options, entry.model.copied())
+        });
+
+        let mut world = World::new();
+        world.insert_resource(registries.clone());
+
+        let added = rebuild_registries_partial(
+            &mut world,
+            vec![(
+                rpath("new"),
+                BlockVariantDescriptor {
+                    options: BlockOptions {
+                        transparency: Transparency::Transparent,
+                        subdividable: false,
+                    },
+                    model: None,
+                    model_path: None,
+                },
+            )],
+        )
+        .unwrap();
+
+        let varreg = registries.get_registry::<BlockVariantRegistry>().unwrap();
+        assert_eq!(vec![BlockVariantId::new(3)], added);
+        assert_eq!(Some(BlockVariantId::new(3)), varreg.get_id(&rpath("new")));
+
+        for (label, id, options, model) in existing {
+            assert_eq!(Some(id), varreg.get_id(&rpath(label)));
+
+            let entry = varreg.get_by_id(id);
+            assert_eq!(options, entry.options);
+            assert_eq!(model, entry.model.copied());
+        }
+    }
 }
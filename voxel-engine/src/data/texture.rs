@@ -7,11 +7,30 @@ use super::{
     registries::{texture::TextureRegistry, Registry},
 };
 
+/// Serialized as a rotation in degrees, see [`FaceTextureRotation::from_degrees`]. Deserializing also accepts
+/// the older raw number of turns (0 to 3), which can't be confused with a valid angle in degrees.
 #[derive(
     Default, Copy, Clone, Debug, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize,
 )]
+#[serde(try_from = "u32", into = "u32")]
 pub struct FaceTextureRotation(u8);
 
+impl TryFrom<u32> for FaceTextureRotation {
+    type Error = FaceTextureRotationParseError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Self::from_degrees(value)
+            .or_else(|| Self::from_turns(value))
+            .ok_or(FaceTextureRotationParseError)
+    }
+}
+
+impl From<FaceTextureRotation> for u32 {
+    fn from(value: FaceTextureRotation) -> Self {
+        value.to_degrees()
+    }
+}
+
 impl ops::Add<Self> for FaceTextureRotation {
     type Output = Self;
     fn add(self, rhs: Self) -> Self::Output {
@@ -56,6 +75,23 @@ impl FaceTextureRotation {
         self.0 as i32 * Self::ONE_TURN_DEG
     }
 
+    /// Get the rotation for the given angle in degrees. Only 0, 90, 180, and 270 are valid angles.
+    pub fn from_degrees(deg: u32) -> Option<Self> {
+        match deg {
+            0 | 90 | 180 | 270 => Some(Self((deg / Self::ONE_TURN_DEG as u32) as u8)),
+            _ => None,
+        }
+    }
+
+    fn from_turns(turns: u32) -> Option<Self> {
+        (turns < Self::TOTAL_ROTATIONS as u32).then_some(Self(turns as u8))
+    }
+
+    /// The inverse of [`FaceTextureRotation::from_degrees`].
+    pub fn to_degrees(self) -> u32 {
+        self.degrees() as u32
+    }
+
     pub fn radians(self) -> f32 {
         self.0 as f32 * Self::ONE_TURN_RAD
     }
@@ -69,9 +105,15 @@ impl FaceTextureRotation {
 pub struct FaceTexture {
     pub rotation: FaceTextureRotation,
     pub id: <TextureRegistry as Registry>::Id,
+    /// RGBA color that the sampled texture color is multiplied with. Lets the same texture
+    /// be reused for differently colored blocks without taking up extra space in the texture atlas.
+    pub tint: [u8; 4],
 }
 
 impl FaceTexture {
+    /// Tint that leaves the texture color unchanged.
+    pub const NO_TINT: [u8; 4] = [u8::MAX; 4];
+
     pub fn color_tex_idx(&self, registry: &TextureRegistry) -> u32 {
         registry.get_by_id(self.id).texture_idx
     }
@@ -80,6 +122,7 @@ impl FaceTexture {
         Self {
             rotation: Default::default(),
             id: texture,
+            tint: Self::NO_TINT,
         }
     }
 
@@ -90,8 +133,21 @@ impl FaceTexture {
         Self {
             rotation,
             id: texture,
+            tint: Self::NO_TINT,
         }
     }
+
+    /// Returns a copy of this face texture with the given RGBA tint.
+    pub fn tinted(self, color: [u8; 4]) -> Self {
+        Self {
+            tint: color,
+            ..self
+        }
+    }
+
+    pub fn is_tinted(&self) -> bool {
+        self.tint != Self::NO_TINT
+    }
 }
 
 #[derive(Copy, Clone, Debug, Default, ShaderType)]
@@ -119,3 +175,48 @@ impl GpuFaceTexture {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn face_texture_rotation_degrees() {
+        for deg in [0, 90, 180, 270] {
+            let rotation = FaceTextureRotation::from_degrees(deg).unwrap();
+            assert_eq!(deg, rotation.to_degrees());
+        }
+
+        assert_eq!(
+            Some(FaceTextureRotation::new(-1)),
+            FaceTextureRotation::from_degrees(270)
+        );
+        assert_eq!(None, FaceTextureRotation::from_degrees(45));
+        assert_eq!(None, FaceTextureRotation::from_degrees(360));
+    }
+
+    #[test]
+    fn deserialize_face_texture_rotation() {
+        assert_eq!(
+            FaceTextureRotation::new(2),
+            serde_json::from_str::<FaceTextureRotation>("180").unwrap()
+        );
+        assert!(serde_json::from_str::<FaceTextureRotation>("45").is_err());
+        assert_eq!(
+            "90",
+            serde_json::to_string(&FaceTextureRotation::new(1)).unwrap()
+        );
+    }
+
+    #[test]
+    fn deserialize_face_texture_rotation_turns() {
+        for turns in 0..FaceTextureRotation::TOTAL_ROTATIONS {
+            assert_eq!(
+                FaceTextureRotation::new(turns),
+                serde_json::from_str::<FaceTextureRotation>(&turns.to_string()).unwrap()
+            );
+        }
+
+        assert!(serde_json::from_str::<FaceTextureRotation>("4").is_err());
+    }
+}
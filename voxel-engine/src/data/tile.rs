@@ -2,7 +2,28 @@ use bevy::prelude::*;
 
 use crate::util::Axis3D;
 
-use super::error::FaceParseError;
+use super::{
+    error::FaceParseError, registries::block::BlockVariantId, texture::FaceTextureRotation,
+};
+
+/// A single face of a block variant, along with the rotation of the texture on that face.
+/// Useful for passing all this information around as one value rather than as separate parameters.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Tile {
+    pub face: Face,
+    pub variant: BlockVariantId,
+    pub rotation: FaceTextureRotation,
+}
+
+impl Tile {
+    pub fn new(face: Face, variant: BlockVariantId, rotation: FaceTextureRotation) -> Self {
+        Self {
+            face,
+            variant,
+            rotation,
+        }
+    }
+}
 
 #[derive(
     Copy,
@@ -3,12 +3,13 @@ use std::str::FromStr;
 use crate::{
     data::{
         error::{
-            BlockModelCreationError, FaceTextureDescParseError, SubmodelFaceTextureDescParseError,
+            BlockModelCreationError, DescriptorError, FaceTextureDescParseError,
+            SubmodelFaceTextureDescParseError,
         },
         registries::{block::BlockOptions, texture::TextureRegistry, Registry},
         resourcepath::ResourcePath,
         texture::{FaceTexture, FaceTextureRotation},
-        tile::Face,
+        tile::{Face, Transparency},
         voxel::SubmodelFaceTexture,
     },
     util::FaceMap,
@@ -23,6 +24,29 @@ use super::{
 pub struct BlockVariantDescriptor {
     pub options: BlockOptions,
     pub model: Option<BlockModelDescriptor>,
+    /// Label of a model in the [`ModelRegistry`](crate::data::registries::model::ModelRegistry) to use
+    /// instead of describing the model inline.
+    #[serde(default)]
+    pub model_path: Option<ResourcePath>,
+}
+
+impl BlockVariantDescriptor {
+    /// Check that this descriptor describes a sensible block variant. This only checks the things that building
+    /// the block model (see [`BlockModelDescriptor::create_block_model`]) doesn't already check.
+    pub fn validate(&self) -> Result<(), DescriptorError> {
+        if self.model.is_some() && self.model_path.is_some() {
+            return Err(DescriptorError::ModelAndModelPath);
+        }
+
+        if self.model.is_none()
+            && self.model_path.is_none()
+            && self.options.transparency == Transparency::Opaque
+        {
+            return Err(DescriptorError::OpaqueWithoutModel);
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, serde::Deserialize)]
@@ -323,6 +347,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn validate_block_variant_descriptor() {
+        let s = r#"
+            [options]
+            transparency = "opaque"
+
+            [model.root]
+            up = "example.face.up"
+        "#;
+
+        let mut de = toml::from_str::<BlockVariantDescriptor>(s).unwrap();
+
+        // Incomplete models are caught when the model is created, not when validating
+        assert_eq!(Ok(()), de.validate());
+
+        de.model_path = Some(rpath("example.model"));
+        assert_eq!(Err(DescriptorError::ModelAndModelPath), de.validate());
+
+        de.model = None;
+        assert_eq!(Ok(()), de.validate());
+
+        de.model_path = None;
+        assert_eq!(Err(DescriptorError::OpaqueWithoutModel), de.validate());
+
+        de.options.transparency = Transparency::Transparent;
+        assert_eq!(Ok(()), de.validate());
+    }
+
     struct Reg;
 
     impl Registry for Reg {
@@ -96,6 +96,26 @@ impl BlockModel {
             model: BlockSubmodel::selfref_no_tex_rot_submodel(BlockModelRotation::DEFAULT),
         }
     }
+
+    /// Create a copy of this model where the face textures are reassigned so that the copy (in the default rotation)
+    /// looks like this model would in the given rotation.
+    /// The rotations of the face textures themselves and the directional submodels are left as they are.
+    pub fn rotate(&self, rotation: BlockModelRotation) -> BlockModel {
+        let model = BlockModelFaceMap::from_fn(|face| {
+            let world_face = BlockModelRotation::DEFAULT.get_cardinal_face(face);
+
+            // Find the face of this model that ends up facing the same way as `face` would by default.
+            BlockModelFace::FACES
+                .into_iter()
+                .find(|&source| rotation.get_cardinal_face(source) == world_face)
+                .and_then(|source| self.model.get(source).copied())
+        });
+
+        BlockModel {
+            directions: self.directions,
+            model,
+        }
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
@@ -136,3 +156,53 @@ impl VoxelModel {
         todo!()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::data::registries::texture::TextureId;
+
+    use super::*;
+
+    fn default_model_face(world_face: Face) -> BlockModelFace {
+        BlockModelFace::FACES
+            .into_iter()
+            .find(|&face| BlockModelRotation::DEFAULT.get_cardinal_face(face) == world_face)
+            .unwrap()
+    }
+
+    #[test]
+    fn rotate_block_model() {
+        let model = BlockModel {
+            directions: FaceMap::new(),
+            model: BlockModelFaceMap::from_fn(|face| {
+                Some(FaceTexture::new(TextureId::new(face.to_usize() as _)))
+            }),
+        };
+
+        let mut orientations = 0;
+
+        for fwd in Face::FACES {
+            for up in Face::FACES {
+                let Some(rotation) = BlockModelRotation::new(fwd, up) else {
+                    continue;
+                };
+
+                orientations += 1;
+                let rotated = model.rotate(rotation);
+
+                for face in BlockModelFace::FACES {
+                    // The texture on this face should end up on whatever world face the rotation puts this face on
+                    let world_face = rotation.get_cardinal_face(face);
+                    assert_eq!(
+                        model.model.get(face),
+                        rotated.model.get(default_model_face(world_face)),
+                        "face {face:?} in rotation {rotation:?}"
+                    );
+                }
+            }
+        }
+
+        assert_eq!(24, orientations);
+        assert_eq!(model, model.rotate(BlockModelRotation::DEFAULT));
+    }
+}
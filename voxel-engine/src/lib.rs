@@ -35,12 +35,19 @@ pub mod util;
 pub mod testing_utils;
 
 use crate::{
-    data::systems::{build_registries, check_textures, load_textures, VariantFolders},
+    data::systems::{
+        build_registries, check_textures, load_added_variant_folders, load_textures,
+        TextureLoadProgress, TextureLoadTimedOut, TextureLoadTimeout, VariantFolderAdded,
+        VariantFolders,
+    },
     render::{core::RenderCore, meshing::controller::MeshController},
     topo::{
         world::{Chunk, ChunkEntity, ChunkPos},
         worldgen::{
-            ecs::{generate_chunks_from_events, setup_terrain_generator_workers, GeneratorSeed},
+            ecs::{
+                dispatch_generated_events, generate_chunks_from_events,
+                setup_terrain_generator_workers, GeneratorSeed,
+            },
             generator::GenerateChunk,
         },
     },
@@ -65,9 +72,42 @@ pub struct VoxelSystemSet;
 pub enum EngineState {
     #[default]
     Setup,
+    /// The engine is set up but worldgen, meshing, and chunk loading are suspended (e.g. during menus).
+    /// The chunk manager is still accessible while paused.
+    /// See [`transition_to_paused`] and [`transition_to_running`].
+    Paused,
     Finished,
+    /// Engine setup failed and the engine won't do anything. An error is logged when transitioning to this state.
+    Error,
+}
+
+/// Pause the engine if it's running. Does nothing if the engine is still being set up.
+pub fn transition_to_paused(
+    state: Res<State<EngineState>>,
+    mut next_state: ResMut<NextState<EngineState>>,
+) {
+    if *state.get() == EngineState::Finished {
+        next_state.set(EngineState::Paused);
+    }
 }
 
+/// Resume the engine if it's paused.
+pub fn transition_to_running(
+    state: Res<State<EngineState>>,
+    mut next_state: ResMut<NextState<EngineState>>,
+) {
+    if *state.get() == EngineState::Paused {
+        next_state.set(EngineState::Finished);
+    }
+}
+
+/// The schedule that core engine setup happens in. This is the transition from [`EngineState::Setup`] to
+/// [`EngineState::Finished`], so that resuming from [`EngineState::Paused`] doesn't set up the engine again.
+pub(crate) const CORE_SETUP_TRANSITION: OnTransition<EngineState> = OnTransition {
+    from: EngineState::Setup,
+    to: EngineState::Finished,
+};
+
 #[derive(Default, Copy, Clone, PartialEq, Eq, Hash, Debug, SystemSet)]
 pub struct CoreEngineSetup;
 
@@ -87,15 +127,20 @@ impl Plugin for VoxelPlugin {
         app.add_plugins(MippedArrayTexturePlugin::default());
 
         app.add_event::<GenerateChunk>();
+        app.add_event::<VariantFolderAdded>();
+        app.add_event::<TextureLoadTimedOut>();
         app.init_state::<EngineState>();
 
         app.insert_resource(VariantFolders::new(self.variant_folders.clone()));
         app.insert_resource(GeneratorSeed(140));
+        app.init_resource::<TextureLoadTimeout>();
+        app.init_resource::<TextureLoadProgress>();
 
         app.add_systems(OnEnter(EngineState::Setup), load_textures);
         app.add_systems(Update, check_textures.run_if(in_state(EngineState::Setup)));
+        app.add_systems(Update, load_added_variant_folders);
         app.add_systems(
-            OnEnter(EngineState::Finished),
+            CORE_SETUP_TRANSITION,
             (build_registries, setup, setup_terrain_generator_workers)
                 .chain()
                 .in_set(CoreEngineSetup),
@@ -103,7 +148,7 @@ impl Plugin for VoxelPlugin {
 
         app.add_systems(
             FixedPostUpdate,
-            generate_chunks_from_events
+            (generate_chunks_from_events, dispatch_generated_events)
                 .run_if(in_state(EngineState::Finished))
                 .after(WorldControllerSystems::CoreEvents),
         );
@@ -50,6 +50,11 @@ use super::{meshing::controller::ExtractableChunkMeshData, quad::GpuQuad};
 
 pub struct RenderCore;
 
+/// Insert this resource into the main world to enable debug visualizations of the render core,
+/// even in release builds.
+#[derive(Resource, Copy, Clone, Debug, Default)]
+pub struct RenderCoreDebug;
+
 impl RenderCore {
     pub const QUAD_INDEX_ATTR: MeshVertexAttribute =
         MeshVertexAttribute::new("quad_index_attr", 5099_0, VertexFormat::Uint32);
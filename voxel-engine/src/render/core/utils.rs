@@ -66,6 +66,8 @@ pub fn add_shader_constants(shader_defs: &mut Vec<ShaderDefVal>) {
         u32_shader_def("FACE_SHIFT", GpuQuadBitfields::FACE_SHIFT),
         u32_shader_def("FLIP_UV_X_BIT", GpuQuadBitfields::FLIP_UV_X_BIT),
         u32_shader_def("FLIP_UV_Y_BIT", GpuQuadBitfields::FLIP_UV_Y_BIT),
+        u32_shader_def("LIGHT_MASK", GpuQuadBitfields::LIGHT_MASK),
+        u32_shader_def("LIGHT_SHIFT", GpuQuadBitfields::LIGHT_SHIFT),
         u32_shader_def("HAS_NORMAL_MAP_BIT", GpuFaceTexture::HAS_NORMAL_MAP_BIT),
         u32_shader_def(
             "CHUNK_OCCLUSION_BUFFER_SIZE",
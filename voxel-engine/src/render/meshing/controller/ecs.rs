@@ -8,20 +8,20 @@ use bevy::{
 use itertools::Itertools;
 
 use crate::{
-    data::{registries::Registries, tile::Face},
+    data::registries::Registries,
     render::meshing::controller::workers::MeshBuilderSettings,
     topo::{
         controller::{PermitFlags, UpdatePermitEvent},
-        world::{chunk::ChunkFlags, Chunk, ChunkPos, VoxelRealm},
+        world::{chunk::ChunkFlags, ChunkPos, VoxelRealm},
         ChunkObserver,
     },
-    util::ChunkMap,
+    util::{ws_to_chunk_pos, ChunkMap, ChunkPosHasher},
 };
 
 use super::{
     workers::{MeshBuilder, MeshCommand},
-    ChunkMeshStatus, ChunkRenderPermit, ExtractableChunkMeshData, RemeshPriority, RemeshType,
-    TimedChunkMeshData,
+    ChunkMeshStatus, ChunkRenderPermit, ExtractableChunkMeshData, MeshingPaused, RemeshPriority,
+    RemeshType, TimedChunkMeshData,
 };
 
 #[derive(Resource, Deref)]
@@ -39,20 +39,44 @@ pub struct RemeshChunk {
 }
 
 /// This system queues meshing jobs in the mesh builder from `RemeshChunk` events.
+/// If meshing is paused the events are buffered (only the latest event per chunk is kept) and queued
+/// once meshing is resumed. Buffered events for chunks that lost their render permit in the meantime are dropped.
 pub fn queue_chunk_mesh_jobs(
     mut builder: ResMut<MeshBuilder>,
     mut events: EventReader<RemeshChunk>,
     mut current_generation: ResMut<MeshGeneration>,
+    paused: Res<MeshingPaused>,
+    realm: VoxelRealm,
+    mut backlog: Local<ChunkMap<RemeshChunk>>,
 ) {
-    if events.len() > 0 {
+    // Events are merged into the backlog so that a chunk is only queued once, even if it was remeshed both
+    // while paused and after resuming. Newer events replace older ones.
+    for event in events.read() {
+        backlog.set(event.pos, event.clone());
+    }
+
+    if paused.0 {
+        return;
+    }
+
+    let remeshings = backlog
+        .drain()
+        .map(|(_, event)| event)
+        .filter(|event| realm.has_render_permit(event.pos))
+        .collect::<Vec<_>>();
+
+    if !remeshings.is_empty() {
         current_generation.0 += 1;
-        debug!("Queuing {} chunks for remeshing from events", events.len());
+        debug!(
+            "Queuing {} chunks for remeshing from events",
+            remeshings.len()
+        );
     }
 
-    let mut commands = Vec::<MeshCommand>::with_capacity(events.len());
+    let mut commands = Vec::<MeshCommand>::with_capacity(remeshings.len());
     let mut immediate = Vec::<MeshCommand>::new();
 
-    for event in events.read() {
+    for event in remeshings.iter() {
         let cmd = MeshCommand {
             pos: event.pos,
             priority: event.priority,
@@ -73,7 +97,18 @@ pub fn queue_chunk_mesh_jobs(
 }
 
 /// This system makes finished chunk meshes available for extraction by the renderer.
-pub fn insert_chunks(workers: Res<MeshBuilder>, mut meshes: ResMut<ExtractableChunkMeshData>) {
+/// Finished meshes are left in the mesh builder while meshing is paused. Meshes for chunks that no longer
+/// have a render permit (i.e., the permit was revoked while the mesh was being built) are dropped.
+pub fn insert_chunks(
+    workers: Res<MeshBuilder>,
+    mut meshes: ResMut<ExtractableChunkMeshData>,
+    paused: Res<MeshingPaused>,
+    realm: VoxelRealm,
+) {
+    if paused.0 {
+        return;
+    }
+
     let mut total = 0;
 
     let finished = workers.get_finished_meshes();
@@ -84,6 +119,10 @@ pub fn insert_chunks(workers: Res<MeshBuilder>, mut meshes: ResMut<ExtractableCh
 
     let mut insert = ChunkMap::<TimedChunkMeshData>::new();
     for mesh in finished.into_iter() {
+        if !realm.has_render_permit(mesh.pos) {
+            continue;
+        }
+
         total += 1;
 
         let Some(existing) = meshes.active.get(mesh.pos) else {
@@ -132,8 +171,8 @@ pub fn remove_chunks(
 }
 
 pub struct UpdateDetectionRemeshResults {
-    primary: hb::HashSet<ChunkPos, fxhash::FxBuildHasher>,
-    neighbors: hb::HashSet<ChunkPos, fxhash::FxBuildHasher>,
+    primary: hb::HashSet<ChunkPos, ChunkPosHasher>,
+    neighbors: hb::HashSet<ChunkPos, ChunkPosHasher>,
 }
 
 /// This system tracks updates in the voxel realm and dispatches remesh events accordingly.
@@ -163,8 +202,8 @@ pub fn voxel_realm_remesh_updated_chunks(
     }
 
     // We need this to keep track of queued chunks, we don't want to queue chunks for remeshing twice!
-    let mut queued_primary = hb::HashSet::<ChunkPos, fxhash::FxBuildHasher>::default();
-    let mut queued_neighbors = hb::HashSet::<ChunkPos, fxhash::FxBuildHasher>::default();
+    let mut queued_primary = hb::HashSet::<ChunkPos, ChunkPosHasher>::default();
+    let mut queued_neighbors = hb::HashSet::<ChunkPos, ChunkPosHasher>::default();
 
     // TODO: skip this update if the chunk manager is globally locked.
     let result = updated.iter_chunks(|cref| {
@@ -189,9 +228,7 @@ pub fn voxel_realm_remesh_updated_chunks(
 
         // This chunk was updated in such a way that we need to remesh its neighbors too!
         if cref.flags().contains(ChunkFlags::REMESH_NEIGHBORS) {
-            for face in Face::FACES {
-                let neighbor_pos = ChunkPos::from(face.normal() + IVec3::from(cref.pos()));
-
+            for neighbor_pos in cref.pos().face_neighbors_iter() {
                 if !realm.has_render_permit(neighbor_pos)
                     || queued_primary.contains(&neighbor_pos)
                     || queued_neighbors.contains(&neighbor_pos)
@@ -256,15 +293,10 @@ pub fn voxel_realm_remesh_updated_chunks(
 }
 
 fn calculate_priority(trans: &Transform, chunk_pos: ChunkPos) -> RemeshPriority {
-    const CHUNK_SIZE_F32: f32 = Chunk::SIZE as f32;
-    const CHUNK_SIZE_DIV2: f32 = CHUNK_SIZE_F32 / 2.0;
-
-    let chunk_center = (chunk_pos.as_vec3() * CHUNK_SIZE_F32) + Vec3::splat(CHUNK_SIZE_DIV2);
-
-    let distance_sq = chunk_center.distance_squared(trans.translation);
-    let distance_sq_int = distance_sq.clamp(0.0, u32::MAX as _) as u32;
+    let observer_pos = ws_to_chunk_pos(trans.translation.floor().as_ivec3());
+    let distance_sq = chunk_pos.distance_euclidean_sq(observer_pos);
 
-    RemeshPriority::new(distance_sq_int)
+    RemeshPriority::new(distance_sq.min(u32::MAX as u64) as u32)
 }
 
 /// This system dispatches remesh jobs for chunks discovered by `voxel_realm_remesh_updated_chunks`
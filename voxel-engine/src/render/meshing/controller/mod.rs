@@ -10,7 +10,7 @@ use crate::{
     render::{meshing::controller::ecs::dispatch_updated_chunk_remeshings, quad::GpuQuad},
     topo::world::ChunkPos,
     util::ChunkMap,
-    CoreEngineSetup, EngineState,
+    CoreEngineSetup, EngineState, CORE_SETUP_TRANSITION,
 };
 
 use self::ecs::{
@@ -97,6 +97,13 @@ pub struct ExtractableChunkMeshData {
     pub removed: Vec<ChunkPos>,
 }
 
+/// Indicates if meshing is paused. While paused no new meshing jobs are queued in the mesh builder and
+/// finished meshes are left in the builder instead of being made available for extraction. Remesh events
+/// received while paused are buffered and queued once meshing is resumed.
+/// See [`MeshController::pause_meshing`] and [`MeshController::resume_meshing`].
+#[derive(Resource, Copy, Clone, Default, Debug, PartialEq, Eq)]
+pub struct MeshingPaused(pub bool);
+
 #[derive(Copy, Clone, PartialEq, dm::Constructor)]
 pub struct ChunkRenderPermit {
     pub granted: u64,
@@ -104,16 +111,30 @@ pub struct ChunkRenderPermit {
 
 pub struct MeshController;
 
+impl MeshController {
+    /// Pause meshing, useful during intense loading phases (like teleporting) where meshes would
+    /// be replaced right after being built anyway. Remesh events are buffered until meshing is resumed.
+    pub fn pause_meshing(cmds: &mut Commands) {
+        cmds.insert_resource(MeshingPaused(true));
+    }
+
+    /// Resume meshing after it was paused with [`MeshController::pause_meshing`].
+    pub fn resume_meshing(cmds: &mut Commands) {
+        cmds.insert_resource(MeshingPaused(false));
+    }
+}
+
 impl Plugin for MeshController {
     fn build(&self, app: &mut App) {
         info!("Setting up mesh controller");
 
         app.init_resource::<ExtractableChunkMeshData>()
             .init_resource::<MeshGeneration>()
+            .init_resource::<MeshingPaused>()
             .add_event::<RemeshChunk>();
 
         app.add_systems(
-            OnEnter(EngineState::Finished),
+            CORE_SETUP_TRANSITION,
             setup_chunk_meshing_workers.after(CoreEngineSetup),
         );
 
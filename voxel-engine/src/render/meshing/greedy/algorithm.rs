@@ -1,7 +1,6 @@
 use bevy::math::ivec2;
 
 use bevy::math::IVec2;
-use bevy::math::Vec2;
 
 use itertools::Itertools;
 
@@ -18,7 +17,6 @@ use crate::render::quad::isometric::IsometrizedQuad;
 use crate::render::quad::isometric::PositionedQuad;
 
 use crate::render::quad::GpuQuad;
-use crate::render::quad::GpuQuadBitfields;
 
 use crate::topo::block::SubdividedBlock;
 use crate::topo::world::CaoBlock;
@@ -201,24 +199,12 @@ impl GreedyMesher {
                 indices.extend_from_slice(&VERTEX_INDICES.map(|idx| idx + current_idx));
                 current_idx += 4;
 
-                let bitfields = GpuQuadBitfields::new()
-                    .with_rotation(quad.quad.dataquad.texture.rotation)
-                    .with_face(quad.isometry.face);
-
-                let magnitude = if quad.isometry.face.axis_direction() > 0 {
-                    quad.isometry.magnitude() + 1
-                } else {
-                    quad.isometry.magnitude()
-                };
-
-                GpuQuad {
-                    // TODO: get rid of these magic numbers
-                    min: quad.min_2d().as_vec2() * 0.25,
-                    max: (quad.max_2d().as_vec2() + Vec2::ONE) * 0.25,
-                    texture_id: quad.quad.dataquad.texture.id.as_u32(),
-                    bitfields,
-                    magnitude,
-                }
+                GpuQuad::from_data_quad(
+                    quad.min_2d(),
+                    &quad.quad.dataquad,
+                    quad.isometry.face,
+                    quad.isometry.magnitude(),
+                )
             })
             .collect_vec();
 
@@ -4,7 +4,7 @@ use crate::{
     data::{
         registries::{block::BlockVariantRegistry, Registry, RegistryRef},
         texture::FaceTexture,
-        tile::Face,
+        tile::{Face, Tile},
         voxel::rotations::BlockModelRotation,
     },
     render::quad::{
@@ -176,7 +176,8 @@ impl<'a, 'chunk> ChunkQuadSlice<'a, 'chunk> {
         if Self::contains_3d(pos) && !neighbors::is_in_bounds_3d(pos) {
             self.get_3d(pos)
         } else if !Self::contains_3d(pos) && neighbors::is_in_bounds_3d(pos) {
-            Ok(self.neighbors.get_3d(pos)?)
+            // SAFETY: we just checked that the position is in bounds for the neighbors
+            Ok(unsafe { self.neighbors.get_3d_unchecked(pos) }?)
         } else {
             return Err(CqsError::OutOfBounds);
         }
@@ -189,7 +190,8 @@ impl<'a, 'chunk> ChunkQuadSlice<'a, 'chunk> {
         if Self::contains_3d(pos) && !neighbors::is_in_bounds_3d(pos) {
             self.get_mb_3d(pos_mb)
         } else if !Self::contains_3d(pos) && neighbors::is_in_bounds_3d(pos) {
-            let nb_block = self.neighbors.get_3d(pos)?.block;
+            // SAFETY: we just checked that the position is in bounds for the neighbors
+            let nb_block = unsafe { self.neighbors.get_3d_unchecked(pos) }?.block;
 
             Ok(match nb_block {
                 CaoBlock::Full(block) => Microblock {
@@ -261,12 +263,28 @@ impl<'a, 'chunk> ChunkQuadSlice<'a, 'chunk> {
         })
     }
 
+    /// Get the light level of the microblock at the given position. `pos_mb` is in micro-facespace.
+    ///
+    /// The engine doesn't have a lighting system yet so this always returns full brightness
+    /// ([`DataQuad::MAX_LIGHT_LEVEL`]). Once there's a light engine this is where light levels should be
+    /// sampled from, most likely from the block "above" the quad since that's the block the face is exposed to.
+    #[inline]
+    pub fn get_quad_light(&self, _pos_mb: IVec2) -> u8 {
+        DataQuad::MAX_LIGHT_LEVEL
+    }
+
     /// Get a quad for given position. This function operates on microblock resolution, so the relevant
     /// block for the provided `pos_mb` is at position `pos_mb / 4` in chunkspace.
     /// Returns `None` if the microblock at the position is obscured by a block "above" it
     /// or if the block at the position doesn't have a model.
     #[inline]
     pub fn get_quad_mb(&self, pos_mb: IVec2) -> CqsResult<Option<DataQuad>> {
+        Ok(self.get_tiled_quad_mb(pos_mb)?.map(|(quad, _)| quad))
+    }
+
+    /// Same as [`ChunkQuadSlice::get_quad_mb`] but also returns the [`Tile`] that the quad was created from.
+    #[inline]
+    pub fn get_tiled_quad_mb(&self, pos_mb: IVec2) -> CqsResult<Option<(DataQuad, Tile)>> {
         let microblock = self.get_mb(pos_mb)?;
         let microblock_above = self.get_mb_above(pos_mb)?;
 
@@ -289,8 +307,12 @@ impl<'a, 'chunk> ChunkQuadSlice<'a, 'chunk> {
             .unwrap_or(model.default_submodel());
 
         let texture = submodel.texture(self.face);
+        let light_level = self.get_quad_light(pos_mb);
 
-        Ok(Some(DataQuad::new(Quad::ONE, texture)))
+        let quad = DataQuad::new(Quad::ONE, texture).with_light_level(light_level);
+        let tile = Tile::new(self.face, microblock.id, texture.rotation);
+
+        Ok(Some((quad, tile)))
     }
 }
 
@@ -301,7 +323,8 @@ pub mod tests {
     use tests::neighbors::NeighborsBuilder;
 
     use crate::{
-        data::registries::texture::TextureRegistry,
+        data::registries::{texture::TextureRegistry, Registries},
+        render::meshing::{greedy::algorithm::GreedyMesher, Context},
         testing_utils::MockChunk,
         topo::{access::WriteAccess, block::BlockVoxel, world::ChunkAccessInput},
     };
@@ -453,6 +476,12 @@ pub mod tests {
             cqs.get_mb(ivec2(16, 16)).unwrap().id
         );
         assert!(cqs.get_quad_mb(ivec2(16, 16)).unwrap().is_some());
+
+        let (_, tile) = cqs.get_tiled_quad_mb(ivec2(16, 16)).unwrap().unwrap();
+        assert_eq!(
+            Tile::new(Face::Top, BlockVariantRegistry::FULL, Default::default()),
+            tile
+        );
     }
 
     #[test]
@@ -582,4 +611,26 @@ pub mod tests {
         assert_eq!(Ok(None), cqs.get_quad_mb(ivec2(33, 30)));
         assert_eq!(Ok(None), cqs.get_quad_mb(ivec2(34, 30)));
     }
+
+    #[test]
+    fn mesher_packs_quad_light() {
+        let texreg = TextureRegistry::new_mock();
+        let registries = Registries::new();
+        registries.add_registry(BlockVariantRegistry::new_mock(&texreg));
+
+        let neighbor_chunk = MockChunk::new(BlockVoxel::new_full(BlockVariantRegistry::VOID));
+        let chunk = testing_chunk();
+
+        let cx = Context {
+            neighbors: testing_neighbors(&neighbor_chunk),
+            registries: &registries,
+        };
+
+        let mesh = GreedyMesher::new().build(chunk.read_access(), cx).unwrap();
+
+        assert!(!mesh.quad_buffer.is_empty());
+        for quad in mesh.quad_buffer {
+            assert_eq!(DataQuad::MAX_LIGHT_LEVEL, quad.bitfields.get_light_level());
+        }
+    }
 }
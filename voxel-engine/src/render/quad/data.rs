@@ -35,14 +35,25 @@ pub struct DataQuad {
     pub quad: Quad,
     pub texture: FaceTexture,
     pub data: QData,
+    /// The light level of this quad, must be between 0 and [`DataQuad::MAX_LIGHT_LEVEL`] (inclusive).
+    pub light_level: u8,
 }
 
 impl DataQuad {
+    pub const MAX_LIGHT_LEVEL: u8 = 15;
+
     pub fn new(quad: Quad, texture: FaceTexture) -> Self {
         Self {
             quad,
             texture,
             data: QData::new(),
+            light_level: Self::MAX_LIGHT_LEVEL,
         }
     }
+
+    pub fn with_light_level(mut self, light_level: u8) -> Self {
+        debug_assert!(light_level <= Self::MAX_LIGHT_LEVEL);
+        self.light_level = light_level;
+        self
+    }
 }
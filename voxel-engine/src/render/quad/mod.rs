@@ -6,7 +6,10 @@ pub mod isometric;
 use std::{fmt::Debug, mem::size_of};
 
 pub use anon::*;
-use bevy::{math::Vec2, render::render_resource::ShaderType};
+use bevy::{
+    math::{IVec2, Vec2},
+    render::render_resource::ShaderType,
+};
 pub use data::*;
 pub use error::*;
 pub use isometric::*;
@@ -29,6 +32,40 @@ pub struct GpuQuad {
     pub min: Vec2,
     pub max: Vec2,
     pub magnitude: i32,
+    /// RGBA tint packed into a single integer, red is in the least significant byte.
+    pub tint: u32,
+}
+
+impl GpuQuad {
+    /// Encode a quad for the GPU. `pos` is the minimum position of the quad in micro-facespace,
+    /// and `magnitude` is the magnitude of the quad's isometry (also in microblocks).
+    pub fn from_data_quad(pos: IVec2, quad: &DataQuad, face: Face, magnitude: i32) -> Self {
+        let bitfields = GpuQuadBitfields::new()
+            .with_rotation(quad.texture.rotation)
+            .with_face(face)
+            .with_light_level(quad.light_level);
+
+        // Quads facing in the positive direction of their axis sit on the far side
+        // of the microblock they belong to.
+        let magnitude = if face.axis_direction() > 0 {
+            magnitude + 1
+        } else {
+            magnitude
+        };
+
+        let min = pos;
+        let max = pos + (quad.quad.dims() - IVec2::ONE);
+
+        Self {
+            // TODO: get rid of these magic numbers
+            min: min.as_vec2() * 0.25,
+            max: (max.as_vec2() + Vec2::ONE) * 0.25,
+            texture_id: quad.texture.id.as_u32(),
+            bitfields,
+            magnitude,
+            tint: u32::from_le_bytes(quad.texture.tint),
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug, ShaderType, PartialEq, Eq)]
@@ -45,6 +82,9 @@ impl GpuQuadBitfields {
     pub const FLIP_UV_X_BIT: u32 = 5;
     pub const FLIP_UV_Y_BIT: u32 = 6;
 
+    pub const LIGHT_MASK: u32 = 0b1111 << 28;
+    pub const LIGHT_SHIFT: u32 = 28;
+
     pub fn new() -> Self {
         Self { value: 0 }
     }
@@ -54,6 +94,10 @@ impl GpuQuadBitfields {
         FromPrimitive::from_u32(raw).unwrap()
     }
 
+    pub fn get_light_level(self) -> u8 {
+        ((self.value & Self::LIGHT_MASK) >> Self::LIGHT_SHIFT) as u8
+    }
+
     pub fn with_rotation(mut self, rotation: FaceTextureRotation) -> Self {
         self.value |= (rotation.inner() as u32) << Self::ROTATION_SHIFT;
         self
@@ -64,6 +108,11 @@ impl GpuQuadBitfields {
         self
     }
 
+    pub fn with_light_level(mut self, light_level: u8) -> Self {
+        self.value |= ((light_level as u32) << Self::LIGHT_SHIFT) & Self::LIGHT_MASK;
+        self
+    }
+
     pub fn with_flip_x(mut self, flip: bool) -> Self {
         if flip {
             self.value |= 0b1 << Self::FLIP_UV_X_BIT;
@@ -102,3 +151,38 @@ impl Debug for ChunkQuads {
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::data::{registries::texture::TextureRegistry, texture::FaceTexture};
+
+    use super::*;
+
+    #[test]
+    fn light_level_round_trip() {
+        for light_level in 0..=DataQuad::MAX_LIGHT_LEVEL {
+            let bitfields = GpuQuadBitfields::new()
+                .with_rotation(FaceTextureRotation::new(3))
+                .with_face(Face::Bottom)
+                .with_light_level(light_level);
+
+            assert_eq!(light_level, bitfields.get_light_level());
+            assert_eq!(Face::Bottom, bitfields.get_face());
+        }
+    }
+
+    #[test]
+    fn from_data_quad_packing() {
+        let texture = FaceTexture::new(TextureRegistry::TEX1).tinted([1, 2, 3, 4]);
+        let quad = DataQuad::new(Quad::ONE, texture).with_light_level(7);
+
+        let gpu_quad = GpuQuad::from_data_quad(IVec2::ZERO, &quad, Face::East, 0);
+
+        assert_eq!(7, gpu_quad.bitfields.get_light_level());
+        assert_eq!(Face::East, gpu_quad.bitfields.get_face());
+
+        // unpack4x8unorm in the shader reads the red channel from the least significant byte
+        let channels = [0, 8, 16, 24].map(|shift| (gpu_quad.tint >> shift) & 0xff);
+        assert_eq!([1, 2, 3, 4], channels);
+    }
+}
@@ -48,6 +48,18 @@ impl BoundingBox {
         pos.cmpge(self.min).all() && pos.cmple(self.max).all()
     }
 
+    /// Returns true if this bounding box overlaps with `other`. Like [`BoundingBox::contains`] the max
+    /// corner is exclusive, so boxes that only share a face do not intersect.
+    pub fn intersects(self, other: BoundingBox) -> bool {
+        self.min.cmplt(other.max).all() && other.min.cmplt(self.max).all()
+    }
+
+    /// Grow this bounding box so that [`BoundingBox::contains`] returns true for `point`.
+    pub fn extend(&mut self, point: IVec3) {
+        self.min = self.min.min(point);
+        self.max = self.max.max(point + IVec3::ONE);
+    }
+
     pub fn to_aabb(self) -> Aabb {
         Aabb::from_min_max(self.min.as_vec3(), self.max.as_vec3())
     }
@@ -79,4 +91,32 @@ mod tests {
         assert!(!bb.contains(IVec3::splat(16)));
         assert!(bb.contains_inclusive(IVec3::splat(16)));
     }
+
+    #[test]
+    fn bounding_box_intersects() {
+        let a = BoundingBox::from_min_max(IVec3::splat(0), IVec3::splat(4));
+        let b = BoundingBox::from_min_max(IVec3::splat(2), IVec3::splat(6));
+        let c = BoundingBox::from_min_max(IVec3::new(4, 0, 0), IVec3::new(8, 4, 4));
+
+        assert!(a.intersects(b));
+        assert!(b.intersects(a));
+        assert!(b.intersects(c));
+
+        // Only sharing a face is not an intersection
+        assert!(!a.intersects(c));
+    }
+
+    #[test]
+    fn bounding_box_extend() {
+        let mut bb = BoundingBox::from_min_max(IVec3::splat(0), IVec3::splat(1));
+
+        bb.extend(IVec3::new(3, -2, 0));
+        assert!(bb.contains(IVec3::new(3, -2, 0)));
+        assert_eq!(IVec3::new(0, -2, 0), bb.min());
+        assert_eq!(IVec3::new(4, 1, 1), bb.max());
+
+        // Extending with a point that's already contained does nothing
+        bb.extend(IVec3::splat(0));
+        assert_eq!(IVec3::new(4, 1, 1), bb.max());
+    }
 }
@@ -1,8 +1,8 @@
 use bevy::prelude::*;
 
-use crate::topo::world::ChunkPos;
+use crate::topo::world::{chunk_manager::ChunkLoadResult, ChunkPos};
 
-use super::{error::EventPosMismatch, LoadReasons, PermitFlags};
+use super::{error::EventPosMismatch, ChunkObserver, LoadReasons, PermitFlags};
 
 pub(super) trait MergeEvent: Sized {
     fn pos(&self) -> ChunkPos;
@@ -30,6 +30,14 @@ pub struct ChunkObserverCrossChunkBorderEvent {
     pub new_chunk: ChunkPos,
 }
 
+/// Replace the settings of a chunk observer. Unlike changing the component directly, this re-evaluates
+/// which chunks should be loaded for the observer right away (as if the observer had moved).
+#[derive(Clone, Event, Debug)]
+pub struct SetObserverSettings {
+    pub entity: Entity,
+    pub settings: ChunkObserver,
+}
+
 /// This chunk should be loaded for the given reasons.
 /// Will either load a chunk with the provided reasons, or add the given load reasons to an
 /// already loaded chunk.
@@ -107,6 +115,32 @@ pub struct UnloadedChunkEvent {
     pub chunk_pos: ChunkPos,
 }
 
+/// Lifecycle event for external plugins, triggered whenever a chunk was successfully loaded by the engine.
+/// Unlike [`LoadedChunkEvent`] this event is also triggered when an already loaded chunk had its load reasons
+/// updated, the provided [`ChunkLoadResult`] describes which of the two happened.
+/// A [`ChunkLoadResult::New`] chunk is still primordial when this event is sent, so it has no block data and
+/// can only be retrieved by asking for primordial chunks. Wait for a [`ChunkGeneratedEvent`] to access its blocks.
+#[derive(Copy, Clone, Event, Debug)]
+pub struct ChunkLoadEvent {
+    pub pos: ChunkPos,
+    pub result: ChunkLoadResult,
+}
+
+/// Lifecycle event for external plugins, triggered when the world generator finished populating a chunk.
+/// The chunk is no longer primordial at this point, but it may have been unloaded again before the event is read.
+#[derive(Copy, Clone, Event, Debug)]
+pub struct ChunkGeneratedEvent {
+    pub pos: ChunkPos,
+}
+
+/// Lifecycle event for external plugins, triggered whenever a chunk was unloaded by the engine.
+/// This is dispatched at the same point in the lifecycle as [`UnloadedChunkEvent`], but is kept as a separate
+/// event type so that external plugins don't compete with the engine's own readers.
+#[derive(Copy, Clone, Event, Debug)]
+pub struct ChunkUnloadEvent {
+    pub pos: ChunkPos,
+}
+
 #[derive(Copy, Clone, Event, Debug)]
 pub struct UpdatePermitEvent {
     pub chunk_pos: ChunkPos,
@@ -1,6 +1,7 @@
 use std::time::Instant;
 
 use bevy::{
+    ecs::system::SystemParam,
     prelude::*,
     render::{primitives::Aabb, view::NoFrustumCulling},
 };
@@ -20,8 +21,9 @@ use crate::{
 };
 
 use super::{
-    ChunkEcsPermits, LoadChunkEvent, LoadedChunkEvent, MergeEvent, Permit, UnloadChunkEvent,
-    UnloadedChunkEvent, UpdatePermitEvent, WorldControllerSettings,
+    ChunkEcsPermits, ChunkLoadEvent, ChunkUnloadEvent, LoadChunkEvent, LoadedChunkEvent,
+    MergeEvent, Permit, UnloadChunkEvent, UnloadedChunkEvent, UpdatePermitEvent,
+    WorldControllerSettings,
 };
 
 #[derive(Bundle)]
@@ -146,6 +148,13 @@ fn update_backlogs<E: Event + Clone + MergeEvent>(
     }
 }
 
+/// Writers for the chunk lifecycle events that are meant for external plugins.
+#[derive(SystemParam)]
+pub struct LifecycleEventWriters<'w> {
+    loads: EventWriter<'w, ChunkLoadEvent>,
+    unloads: EventWriter<'w, ChunkUnloadEvent>,
+}
+
 pub fn handle_chunk_loads_and_unloads(
     // Prelude
     realm: VoxelRealm,
@@ -158,6 +167,7 @@ pub fn handle_chunk_loads_and_unloads(
     mut loaded_chunks: EventWriter<LoadedChunkEvent>,
     mut unload_events: EventReader<UnloadChunkEvent>,
     mut unloaded_chunks: EventWriter<UnloadedChunkEvent>,
+    mut lifecycle: LifecycleEventWriters,
     // Backlogs
     mut unload_backlog: Local<ChunkMap<UnloadChunkEvent>>,
     mut load_backlog: Local<ChunkMap<LoadChunkEvent>>,
@@ -212,6 +222,9 @@ pub fn handle_chunk_loads_and_unloads(
                             unloaded_chunks.send(UnloadedChunkEvent {
                                 chunk_pos: event.chunk_pos,
                             });
+                            lifecycle.unloads.send(ChunkUnloadEvent {
+                                pos: event.chunk_pos,
+                            });
                         }
                     }
                     Err(error) => {
@@ -245,6 +258,11 @@ pub fn handle_chunk_loads_and_unloads(
                     }
                 };
 
+                lifecycle.loads.send(ChunkLoadEvent {
+                    pos: event.chunk_pos,
+                    result,
+                });
+
                 // If the chunk wasn't loaded before and the event wants to generate the chunk,
                 // dispatch a generation event.
                 if result == ChunkLoadResult::New {
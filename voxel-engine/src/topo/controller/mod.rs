@@ -6,7 +6,7 @@ use error::EventPosMismatch;
 use handle_events::{handle_chunk_loads_and_unloads, handle_permit_updates};
 use observer_events::{
     dispatch_move_events, generate_chunks_with_priority, load_in_range_chunks,
-    unload_out_of_range_chunks,
+    set_observer_settings, unload_out_of_range_chunks,
 };
 
 use crate::EngineState;
@@ -55,6 +55,18 @@ bitflags! {
     }
 }
 
+impl LoadReasons {
+    /// Returns true if all the flags in `other` are set in `self`.
+    pub fn is_superset_of(self, other: LoadReasons) -> bool {
+        self.contains(other)
+    }
+
+    /// Returns true if all the flags in `self` are set in `other`.
+    pub fn is_subset_of(self, other: LoadReasons) -> bool {
+        other.contains(self)
+    }
+}
+
 impl fmt::Debug for LoadReasons {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let permit_flag_names = [
@@ -101,15 +113,23 @@ impl Plugin for WorldController {
             .add_event::<LoadedChunkEvent>()
             .add_event::<UnloadChunkEvent>()
             .add_event::<UnloadedChunkEvent>()
+            .add_event::<ChunkLoadEvent>()
+            .add_event::<ChunkGeneratedEvent>()
+            .add_event::<ChunkUnloadEvent>()
             .add_event::<UpdatePermitEvent>()
             .add_event::<ChunkObserverMoveEvent>()
-            .add_event::<ChunkObserverCrossChunkBorderEvent>();
+            .add_event::<ChunkObserverCrossChunkBorderEvent>()
+            .add_event::<SetObserverSettings>();
 
         app.add_systems(
             FixedPostUpdate,
             (
                 dispatch_move_events.in_set(WorldControllerSystems::ObserverMovement),
-                (unload_out_of_range_chunks, load_in_range_chunks)
+                (
+                    set_observer_settings,
+                    unload_out_of_range_chunks,
+                    load_in_range_chunks,
+                )
                     .chain()
                     .in_set(WorldControllerSystems::ObserverResponses),
                 (handle_chunk_loads_and_unloads, handle_permit_updates)
@@ -14,7 +14,7 @@ use cb::channel;
 use crate::{
     render::meshing::controller::MeshGeneration,
     topo::{
-        world::{realm::ChunkManagerResource, Chunk, ChunkEntity, ChunkPos, VoxelRealm},
+        world::{realm::ChunkManagerResource, ChunkEntity, ChunkPos, VoxelRealm},
         worldgen::{generator::GenerateChunk, GenerationPriority},
     },
     util::{ws_to_chunk_pos, ChunkMap, ChunkSet},
@@ -23,7 +23,7 @@ use crate::{
 use super::{
     ChunkObserver, ChunkObserverCrossChunkBorderEvent, ChunkObserverMoveEvent, ChunkPermitKey,
     Entry, LastPosition, LoadChunkEvent, LoadReasons, LoadedChunkEvent, Permit, PermitFlags,
-    UnloadChunkEvent, UpdatePermitEvent,
+    SetObserverSettings, UnloadChunkEvent, UpdatePermitEvent,
 };
 
 fn transform_chunk_pos(trans: &Transform) -> ChunkPos {
@@ -100,6 +100,43 @@ pub fn dispatch_move_events(
     }
 }
 
+/// Apply new observer settings and send move events for the observers so that their chunks are re-evaluated.
+pub fn set_observer_settings(
+    mut events: EventReader<SetObserverSettings>,
+    mut observers: Query<(&mut ChunkObserver, Option<&LastPosition>)>,
+    mut move_events: EventWriter<ChunkObserverMoveEvent>,
+    mut chunk_border_events: EventWriter<ChunkObserverCrossChunkBorderEvent>,
+) {
+    for event in events.read() {
+        let Ok((mut observer, last_pos)) = observers.get_mut(event.entity) else {
+            error!("Entity in observer settings event was not a chunk observer");
+            continue;
+        };
+
+        *observer = event.settings.clone();
+
+        // Observers without a last position haven't had their chunks loaded yet, so they'll be handled
+        // with the new settings when their first move events are dispatched.
+        let Some(last_pos) = last_pos else {
+            continue;
+        };
+
+        move_events.send(ChunkObserverMoveEvent {
+            new: false,
+            entity: event.entity,
+            old_pos: last_pos.ws_pos,
+            new_pos: last_pos.ws_pos,
+        });
+
+        chunk_border_events.send(ChunkObserverCrossChunkBorderEvent {
+            new: false,
+            entity: event.entity,
+            old_chunk: last_pos.chunk_pos,
+            new_chunk: last_pos.chunk_pos,
+        });
+    }
+}
+
 fn chunk_pos_center_vec3(pos: ChunkPos) -> Vec3 {
     pos.as_vec3() + Vec3::splat(0.5)
 }
@@ -273,15 +310,8 @@ pub fn load_in_range_chunks(
 }
 
 fn calculate_priority(trans: &Transform, chunk_pos: ChunkPos) -> GenerationPriority {
-    const CHUNK_SIZE_F32: f32 = Chunk::SIZE as f32;
-    const CHUNK_SIZE_DIV2: f32 = CHUNK_SIZE_F32 / 2.0;
-
-    let chunk_center = (chunk_pos.as_vec3() * CHUNK_SIZE_F32) + Vec3::splat(CHUNK_SIZE_DIV2);
-
-    let distance_sq = chunk_center.distance_squared(trans.translation);
-    let distance_sq_int = distance_sq.clamp(0.0, u32::MAX as _) as u32;
-
-    GenerationPriority::new(distance_sq_int)
+    let distance_sq = chunk_pos.distance_euclidean_sq(transform_chunk_pos(trans));
+    GenerationPriority::new(distance_sq.min(u32::MAX as u64) as u32)
 }
 
 pub fn generate_chunks_with_priority(
@@ -289,14 +319,12 @@ pub fn generate_chunks_with_priority(
     mut loaded_chunks: EventReader<LoadedChunkEvent>,
     mut generation_events: EventWriter<GenerateChunk>,
 ) {
-    let mut chunks_to_gen = ChunkSet::default();
-
     // We only care about auto_generate chunks
-    for chunk in loaded_chunks.read() {
-        if chunk.auto_generate {
-            chunks_to_gen.set(chunk.chunk_pos);
-        }
-    }
+    let chunks_to_gen = loaded_chunks
+        .read()
+        .filter(|chunk| chunk.auto_generate)
+        .map(|chunk| chunk.chunk_pos)
+        .collect::<ChunkSet>();
 
     generation_events.send_batch(chunks_to_gen.iter().map(|chunk_pos| {
         // Calculate priority based on distance to nearest observer, if there's no observers we use
@@ -1,18 +1,21 @@
 use bevy::math::{ivec3, IVec2, IVec3};
 
 use crate::{
-    data::tile::Face,
+    data::{registries::block::BlockVariantId, tile::Face},
     topo::{
         access::ReadAccess, bounding_box::BoundingBox, ivec_project_to_3d,
         storage::error::OutOfBounds,
     },
-    util::ivec3_to_1d,
+    util::{ivec3_to_1d, to_1d},
 };
 
 use super::{
     block::BlockVoxel,
     error::NeighborAccessError,
-    world::{chunk_ref::Crra, Chunk, ChunkAccessOutput},
+    world::{
+        chunk_ref::{CaoBlock, Crra},
+        Chunk, ChunkAccessOutput,
+    },
 };
 
 fn localspace_to_chunk_pos(pos: IVec3) -> IVec3 {
@@ -114,6 +117,52 @@ impl<'a> Neighbors<'a> {
 
         self.internal_get(pos)
     }
+
+    /// Like [`Neighbors::get_3d`] but skips all bounds checking. Intended for hot paths (like meshing) where
+    /// the position has already been verified.
+    ///
+    /// # Safety
+    /// [`is_in_bounds_3d`] must return true for `pos` (in localspace), otherwise this may read out of bounds.
+    #[inline]
+    pub unsafe fn get_3d_unchecked(&self, pos: IVec3) -> NbResult<'_> {
+        let [x, y, z] = (localspace_to_chunk_pos(pos) + IVec3::ONE)
+            .as_uvec3()
+            .to_array()
+            .map(|v| v as usize);
+
+        let chk_index = to_1d(x, y, z, NEIGHBOR_CUBIC_ARRAY_DIMENSIONS);
+        // SAFETY: the caller guarantees that the position is within the neighbors, so the index
+        // of the neighbor chunk is within the neighbor array.
+        let chk = unsafe { self.chunks.get_unchecked(chk_index) };
+
+        match chk {
+            Some(access) => {
+                let neighbor_local = localspace_to_neighbor_localspace(pos);
+                // SAFETY: positions in neighbor localspace are always within the chunk bounds
+                Ok(unsafe { access.get_unchecked(neighbor_local) }?)
+            }
+            None => Ok(ChunkAccessOutput::new(&self.default)),
+        }
+    }
+
+    /// Get the block variant of the full block at `pos` (in localspace) without any bounds checking.
+    /// Intended for inner loops where the position has already been verified.
+    ///
+    /// # Safety
+    /// [`is_in_bounds_3d`] must return true for `pos`, otherwise this may read out of bounds.
+    ///
+    /// # Panics
+    /// Panics if the block at `pos` is subdivided or hasn't been initialized.
+    #[inline]
+    pub unsafe fn get_full_block_unchecked(&self, pos: IVec3) -> BlockVariantId {
+        // SAFETY: upheld by the caller
+        let output = unsafe { self.get_3d_unchecked(pos) }.expect("block must be initialized");
+
+        match output.block {
+            CaoBlock::Full(block) => block.id,
+            CaoBlock::Subdivided(_) => panic!("block at {pos} is subdivided"),
+        }
+    }
 }
 
 fn is_valid_neighbor_chunk_pos(pos: IVec3) -> bool {
@@ -146,317 +195,207 @@ impl<'a> NeighborsBuilder<'a> {
         Ok(())
     }
 
+    /// Set all the neighbors in the given iterator. Either all neighbors are set, or (if any of the
+    /// positions were invalid) none of them are.
+    pub fn set_all<I>(&mut self, neighbors: I) -> Result<(), OutOfBounds>
+    where
+        I: IntoIterator<Item = (IVec3, Crra<'a>)>,
+    {
+        let mut chunks: [Option<Crra<'a>>; NEIGHBOR_ARRAY_SIZE] = Default::default();
+
+        for (pos, access) in neighbors {
+            if !is_valid_neighbor_chunk_pos(pos) {
+                return Err(OutOfBounds);
+            }
+
+            let idx = ivec3_to_1d(pos + IVec3::ONE, NEIGHBOR_CUBIC_ARRAY_DIMENSIONS)
+                .map_err(|_| OutOfBounds)?;
+
+            chunks[idx] = Some(access);
+        }
+
+        for (slot, access) in self.0.chunks.iter_mut().zip(chunks) {
+            if access.is_some() {
+                *slot = access;
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn build(self) -> Neighbors<'a> {
         self.0
     }
 }
 
-/* TODO: fix this madness
-
 #[cfg(test)]
 mod tests {
     use bevy::math::ivec2;
 
     use crate::{
-        data::{
-            registries::{block::{BlockVariantId, BlockVariantRegistry}, Registry},
-            tile::Transparency,
+        data::registries::block::BlockVariantRegistry,
+        testing_utils::MockChunk,
+        topo::{
+            access::WriteAccess, block::Microblock, block::SubdividedBlock, world::ChunkAccessInput,
         },
-        topo::{access::{self, HasBounds}, block::FullBlock},
     };
 
     use super::*;
 
-    fn make_blockvxl(id: u32) -> BlockVoxel {
-        BlockVoxel::Full(FullBlock {
-            rotation: None,
-            block: BlockVariantId::new(id)
-        })
-    }
-
-    struct TestAccess {
-        even: BlockVoxel,
-        odd: BlockVoxel,
-    }
-
-    impl ChunkBounds for TestAccess {}
-    impl access::ReadAccess for TestAccess {
-        type ReadErr = OutOfBounds;
-        type ReadType = ChunkVoxelOutput<'a>;
-
-        fn get(&self, pos: IVec3) -> Result<Self::ReadType, Self::ReadErr> {
-            if !self.bounds().contains(pos) {
-                return Err(OutOfBounds);
-            }
-
-            if (pos % 2).cmpeq(IVec3::ZERO).any() {
-                Ok(ChunkVoxelOutput::new(&self.even))
-            } else {
-                Ok(ChunkVoxelOutput::new(&self.odd))
-            }
+    fn block_id(output: ChunkAccessOutput) -> BlockVariantId {
+        match output.block {
+            CaoBlock::Full(block) => block.id,
+            CaoBlock::Subdivided(_) => panic!("expected full block"),
         }
     }
 
-    fn make_test_neighbors<'a>() -> Neighbors<'a, TestAccess> {
-        let mut builder = NeighborsBuilder::<TestAccess>::new(make_blockvxl(0));
-
-        // FACES
-
-        builder
-            .set_neighbor(ivec3(1, 0, 0), TestAccess { even: 1, odd: 2 })
-            .unwrap();
-
-        builder
-            .set_neighbor(ivec3(-1, 0, 0), TestAccess { even: 3, odd: 4 })
-            .unwrap();
-
-        builder
-            .set_neighbor(ivec3(0, 1, 0), TestAccess { even: 5, odd: 6 })
-            .unwrap();
-
-        builder
-            .set_neighbor(ivec3(0, -1, 0), TestAccess { even: 7, odd: 8 })
-            .unwrap();
-
-        builder
-            .set_neighbor(ivec3(0, 0, 1), TestAccess { even: 9, odd: 10 })
-            .unwrap();
-
-        builder
-            .set_neighbor(ivec3(0, 0, -1), TestAccess { even: 11, odd: 12 })
-            .unwrap();
-
-        // EDGES
-
-        builder
-            .set_neighbor(ivec3(1, 1, 0), TestAccess { even: 20, odd: 21 })
-            .unwrap();
-
-        builder
-            .set_neighbor(ivec3(-1, 1, 0), TestAccess { even: 22, odd: 23 })
-            .unwrap();
-
-        builder
-            .set_neighbor(ivec3(0, 1, 1), TestAccess { even: 24, odd: 25 })
-            .unwrap();
-
-        builder
-            .set_neighbor(ivec3(0, 1, -1), TestAccess { even: 26, odd: 27 })
-            .unwrap();
-
-        // CORNERS
-
-        builder
-            .set_neighbor(ivec3(1, 1, 1), TestAccess { even: 30, odd: 31 })
-            .unwrap();
-
-        builder
-            .set_neighbor(ivec3(-1, 1, 1), TestAccess { even: 32, odd: 33 })
-            .unwrap();
-
-        builder
-            .set_neighbor(ivec3(1, 1, -1), TestAccess { even: 34, odd: 35 })
-            .unwrap();
-
-        builder
-            .set_neighbor(ivec3(-1, 1, -1), TestAccess { even: 36, odd: 37 })
-            .unwrap();
+    fn testing_chunk(id: u32) -> MockChunk {
+        let chunk = MockChunk::new(BlockVoxel::new_full(BlockVariantRegistry::VOID));
+        let mut access = chunk.access();
+
+        for i in 0..Chunk::SIZE {
+            access
+                .set(
+                    ivec3(i, i, i),
+                    ChunkAccessInput::new(BlockVoxel::new_full(BlockVariantId::new(id))),
+                )
+                .unwrap();
+        }
 
-        builder.build()
+        drop(access);
+        chunk
     }
 
     #[test]
     fn test_builder() {
-        const DUMMY: TestAccess = TestAccess { even: 0, odd: 0 };
-
-        let mut builder = NeighborsBuilder::<TestAccess>::new(make_blockvxl(0));
-
-        assert!(builder.set_neighbor(ivec3(0, 0, 0), DUMMY).is_err());
-        assert!(builder.set_neighbor(ivec3(1, 1, 1), DUMMY).is_ok());
-        assert!(builder.set_neighbor(ivec3(-1, -1, -1), DUMMY).is_ok());
-        assert!(builder.set_neighbor(ivec3(-1, -2, -1), DUMMY).is_err());
+        let chunk = MockChunk::new(BlockVoxel::new_full(BlockVariantRegistry::VOID));
+        let mut builder = NeighborsBuilder::new(BlockVoxel::new_full(BlockVariantRegistry::VOID));
+
+        assert!(builder
+            .set_neighbor(ivec3(0, 0, 0), chunk.read_access())
+            .is_err());
+        assert!(builder
+            .set_neighbor(ivec3(1, 1, 1), chunk.read_access())
+            .is_ok());
+        assert!(builder
+            .set_neighbor(ivec3(-1, -1, -1), chunk.read_access())
+            .is_ok());
+        assert!(builder
+            .set_neighbor(ivec3(-1, -2, -1), chunk.read_access())
+            .is_err());
     }
 
     #[test]
     fn test_neighbors() {
-        let neighbors = make_test_neighbors();
-
-        dbg!(ivec3(0, 0, 0) % 2);
-
-        assert_eq!(
-            0,
-            neighbors
-                .get(Face::Bottom, ivec2(-1, 0))
-                .unwrap()
-                .variant
-                .as_u32()
-        );
+        let top = testing_chunk(5);
+        let corner = testing_chunk(30);
 
-        assert_eq!(
-            7,
-            neighbors
-                .get(Face::Bottom, ivec2(0, 0))
-                .unwrap()
-                .variant
-                .as_u32()
-        );
-        assert_eq!(
-            8,
-            neighbors
-                .get(Face::Bottom, ivec2(1, 1))
-                .unwrap()
-                .variant
-                .as_u32()
-        );
-
-        assert_eq!(
-            7,
-            neighbors
-                .get(Face::Bottom, ivec2(6, 10))
-                .unwrap()
-                .variant
-                .as_u32()
-        );
-        assert_eq!(
-            8,
-            neighbors
-                .get(Face::Bottom, ivec2(5, 5))
-                .unwrap()
-                .variant
-                .as_u32()
-        );
-
-        assert_eq!(
-            0,
-            neighbors
-                .get(Face::Bottom, ivec2(16, 16))
-                .unwrap()
-                .variant
-                .as_u32()
-        );
-
-        assert!(neighbors.get(Face::Bottom, ivec2(16, 17)).is_err());
-        assert!(neighbors.get(Face::Bottom, ivec2(-2, 5)).is_err());
-
-        // EDGES
-
-        assert_eq!(
-            5,
-            neighbors
-                .get(Face::Top, ivec2(0, 0))
-                .unwrap()
-                .variant
-                .as_u32()
-        );
-
-        assert_eq!(
-            20,
-            neighbors
-                .get(Face::Top, ivec2(16, 5))
-                .unwrap()
-                .variant
-                .as_u32()
-        );
+        let mut builder = NeighborsBuilder::new(BlockVoxel::new_full(BlockVariantRegistry::FULL));
+        builder
+            .set_neighbor(ivec3(0, 1, 0), top.read_access())
+            .unwrap();
+        builder
+            .set_neighbor(ivec3(1, 1, 1), corner.read_access())
+            .unwrap();
+        let neighbors = builder.build();
 
         assert_eq!(
-            20,
-            neighbors
-                .get(Face::North, ivec2(6, 16))
-                .unwrap()
-                .variant
-                .as_u32()
+            BlockVariantId::new(5),
+            block_id(neighbors.get(Face::Top, ivec2(0, 0)).unwrap())
         );
-
         assert_eq!(
-            1,
-            neighbors
-                .get(Face::North, ivec2(6, 6))
-                .unwrap()
-                .variant
-                .as_u32()
+            BlockVariantRegistry::VOID,
+            block_id(neighbors.get(Face::Top, ivec2(0, 1)).unwrap())
         );
-
         assert_eq!(
-            22,
-            neighbors
-                .get(Face::Top, ivec2(-1, 5))
-                .unwrap()
-                .variant
-                .as_u32()
+            BlockVariantId::new(30),
+            block_id(neighbors.get(Face::Top, ivec2(16, 16)).unwrap())
         );
-
+        // Neighbors that aren't set use the default block
         assert_eq!(
-            24,
-            neighbors
-                .get(Face::Top, ivec2(5, 16))
-                .unwrap()
-                .variant
-                .as_u32()
+            BlockVariantRegistry::FULL,
+            block_id(neighbors.get(Face::Bottom, ivec2(5, 5)).unwrap())
         );
 
-        assert_eq!(
-            26,
-            neighbors
-                .get(Face::Top, ivec2(5, -1))
-                .unwrap()
-                .variant
-                .as_u32()
-        );
+        assert!(neighbors.get(Face::Top, ivec2(16, 17)).is_err());
+        assert!(neighbors.get(Face::Top, ivec2(-2, 5)).is_err());
+    }
 
-        // CORNERS
+    #[test]
+    fn test_neighbors_3d() {
+        let top = testing_chunk(5);
 
-        assert_eq!(
-            30,
-            neighbors
-                .get(Face::Top, ivec2(16, 16))
-                .unwrap()
-                .variant
-                .as_u32()
-        );
+        let mut builder = NeighborsBuilder::new(BlockVoxel::new_full(BlockVariantRegistry::FULL));
+        builder
+            .set_neighbor(ivec3(0, 1, 0), top.read_access())
+            .unwrap();
+        let neighbors = builder.build();
 
         assert_eq!(
-            32,
-            neighbors
-                .get(Face::Top, ivec2(-1, 16))
-                .unwrap()
-                .variant
-                .as_u32()
+            BlockVariantId::new(5),
+            block_id(neighbors.get_3d(ivec3(0, 16, 0)).unwrap())
         );
-
+        assert!(neighbors.get_3d(ivec3(17, 5, 5)).is_err());
+        assert!(neighbors.get_3d(ivec3(5, 5, 5)).is_err());
         assert_eq!(
-            34,
-            neighbors
-                .get(Face::Top, ivec2(16, -1))
-                .unwrap()
-                .variant
-                .as_u32()
+            BlockVariantRegistry::FULL,
+            block_id(neighbors.get_3d(ivec3(-1, 5, 5)).unwrap())
         );
+    }
 
-        assert_eq!(
-            36,
-            neighbors
-                .get(Face::Top, ivec2(-1, -1))
-                .unwrap()
-                .variant
-                .as_u32()
-        );
+    #[test]
+    fn get_full_block_unchecked_matches_get_3d() {
+        let chunks = (0..NEIGHBOR_ARRAY_SIZE as u32)
+            .map(testing_chunk)
+            .collect::<Vec<_>>();
+
+        let mut builder = NeighborsBuilder::new(BlockVoxel::new_full(BlockVariantRegistry::VOID));
+        for x in -1..=1 {
+            for y in -1..=1 {
+                for z in -1..=1 {
+                    let p = ivec3(x, y, z);
+                    if p == IVec3::ZERO {
+                        continue;
+                    }
+
+                    let idx = ivec3_to_1d(p + IVec3::ONE, NEIGHBOR_CUBIC_ARRAY_DIMENSIONS).unwrap();
+                    builder.set_neighbor(p, chunks[idx].read_access()).unwrap();
+                }
+            }
+        }
+        let neighbors = builder.build();
+
+        for x in -1..=Chunk::SIZE {
+            for y in -1..=Chunk::SIZE {
+                for z in -1..=Chunk::SIZE {
+                    let pos = ivec3(x, y, z);
+                    if !is_in_bounds_3d(pos) {
+                        continue;
+                    }
+
+                    let expected = block_id(neighbors.get_3d(pos).unwrap());
+                    // SAFETY: we just checked that the position is in bounds
+                    assert_eq!(expected, unsafe { neighbors.get_full_block_unchecked(pos) });
+                }
+            }
+        }
     }
 
     #[test]
-    fn test_neighbors_3d() {
-        let neighbors = make_test_neighbors();
+    #[should_panic]
+    fn get_full_block_unchecked_subdivided() {
+        let chunk = MockChunk::new(BlockVoxel::Subdivided(SubdividedBlock::new(
+            Microblock::new(BlockVariantRegistry::VOID),
+        )));
 
-        assert_eq!(
-            1,
-            neighbors.get_3d(ivec3(16, 5, 5)).unwrap().variant.as_u32()
-        );
-        assert!(neighbors.get_3d(ivec3(17, 5, 5)).is_err());
-        assert!(neighbors.get_3d(ivec3(5, 5, 5)).is_err());
+        let mut builder = NeighborsBuilder::new(BlockVoxel::new_full(BlockVariantRegistry::VOID));
+        builder
+            .set_neighbor(ivec3(1, 0, 0), chunk.read_access())
+            .unwrap();
+        let neighbors = builder.build();
 
-        assert_eq!(
-            4,
-            neighbors.get_3d(ivec3(-1, 5, 5)).unwrap().variant.as_u32()
-        );
+        // SAFETY: the position is in bounds
+        unsafe { neighbors.get_full_block_unchecked(ivec3(16, 5, 5)) };
     }
 
     #[test]
@@ -487,6 +426,3 @@ mod tests {
         assert_eq!(ivec3(0, 0, 5), f(0, 16, 5));
     }
 }
-
-
-*/
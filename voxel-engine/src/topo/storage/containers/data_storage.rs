@@ -111,6 +111,12 @@ impl<T: hash::Hash + Eq, S: BuildHasher> SyncIndexedChunkContainer<T, S> {
     pub fn read_access(&self) -> SiccReadAccess<'_, T, S> {
         SiccReadAccess(self.0.read())
     }
+
+    /// Like [`SyncIndexedChunkContainer::read_access`] but returns `None` instead of blocking if the
+    /// container is currently locked for writing.
+    pub fn try_read_access(&self) -> Option<SiccReadAccess<'_, T, S>> {
+        self.0.try_read().map(SiccReadAccess)
+    }
 }
 
 pub struct SiccAccess<'a, T: hash::Hash + Eq, S: BuildHasher>(
@@ -180,3 +186,13 @@ impl<'a, T: hash::Hash + Eq, S: BuildHasher> ReadAccess for SiccReadAccess<'a, T
         Ok(self.0.get(pos)?)
     }
 }
+
+impl<'a, T: hash::Hash + Eq, S: BuildHasher> SiccReadAccess<'a, T, S> {
+    /// # Safety
+    /// `pos` must be within the chunk, see [`IndexedChunkStorage::get_unchecked`].
+    #[inline]
+    pub unsafe fn get_unchecked(&self, pos: IVec3) -> Option<&T> {
+        // SAFETY: upheld by the caller
+        unsafe { self.0.get_unchecked(pos) }
+    }
+}
@@ -35,6 +35,15 @@ impl<T> DenseChunkStorage<T> {
         let [x, y, z] = idx;
         self.0.get_mut(x)?.get_mut(y)?.get_mut(z)
     }
+
+    /// # Safety
+    /// All components of `idx` must be less than [`Chunk::USIZE`].
+    #[inline]
+    pub unsafe fn get_ref_unchecked(&self, idx: [usize; 3]) -> &T {
+        let [x, y, z] = idx;
+        // SAFETY: the caller guarantees that the index is in bounds
+        unsafe { self.0.get_unchecked(x).get_unchecked(y).get_unchecked(z) }
+    }
 }
 
 type SqChunkArray<T> = SquareArray<{ Chunk::USIZE }, Option<T>>;
@@ -431,6 +440,24 @@ impl<T: Eq + hash::Hash, S: BuildHasher> IndexedChunkStorage<T, S> {
         Ok(Some(&self.values[idx]))
     }
 
+    /// Like [`IndexedChunkStorage::get`] but without bounds checking.
+    ///
+    /// # Safety
+    /// `pos` must be within the chunk bounds.
+    #[inline]
+    pub unsafe fn get_unchecked(&self, pos: IVec3) -> Option<&T> {
+        let us = pos.as_uvec3().to_array().map(|v| v as usize);
+        // SAFETY: the caller guarantees that the position is within the chunk
+        let idx = *unsafe { self.indices.get_ref_unchecked(us) };
+
+        if idx != Self::EMPTY_VALUE {
+            // SAFETY: every index stored in `indices` (that isn't the empty value) points to a value
+            Some(unsafe { self.values.get_unchecked(idx as usize) })
+        } else {
+            None
+        }
+    }
+
     pub fn get_mut(&mut self, pos: IVec3) -> Result<Option<&mut T>, OutOfBounds> {
         if !Self::contains_pos(pos) {
             return Err(OutOfBounds);
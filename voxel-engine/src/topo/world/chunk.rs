@@ -8,6 +8,7 @@ use parking_lot::RwLock;
 
 use crate::data::registries::block::BlockVariantRegistry;
 use crate::data::registries::Registry;
+use crate::data::tile::Face;
 use crate::data::voxel::rotations::BlockModelRotation;
 use crate::topo::block::{BlockVoxel, SubdividedBlock};
 use crate::topo::bounding_box::BoundingBox;
@@ -51,6 +52,42 @@ impl ChunkPos {
     pub fn as_vec3(self) -> Vec3 {
         self.0.as_vec3()
     }
+
+    /// The difference between two chunk positions along each axis. Computed with 64 bit integers so it
+    /// can't overflow.
+    fn axis_distances(self, other: ChunkPos) -> [u64; 3] {
+        let [a, b] = [self.0, other.0].map(|v| v.to_array().map(i64::from));
+        [0, 1, 2].map(|i| (a[i] - b[i]).unsigned_abs())
+    }
+
+    /// The chebyshev distance (the largest difference along any axis) between two chunk positions.
+    pub fn distance_chebyshev(self, other: ChunkPos) -> u32 {
+        // The difference between two i32s always fits in a u32
+        self.axis_distances(other).into_iter().max().unwrap() as u32
+    }
+
+    /// The squared euclidean distance between two chunk positions.
+    pub fn distance_euclidean_sq(self, other: ChunkPos) -> u64 {
+        self.axis_distances(other)
+            .into_iter()
+            .map(|d| d * d)
+            .fold(0, u64::saturating_add)
+    }
+
+    /// Iterate over the 26 chunk positions surrounding this one (not including this one).
+    pub fn neighbors_iter(self) -> impl Iterator<Item = ChunkPos> {
+        (-1..=1)
+            .flat_map(|x| (-1..=1).flat_map(move |y| (-1..=1).map(move |z| ivec3(x, y, z))))
+            .filter(|&offset| offset != IVec3::ZERO)
+            .map(move |offset| Self(self.0 + offset))
+    }
+
+    /// Iterate over the 6 chunk positions sharing a face with this one.
+    pub fn face_neighbors_iter(self) -> impl Iterator<Item = ChunkPos> {
+        Face::FACES
+            .into_iter()
+            .map(move |face| Self(self.0 + face.normal()))
+    }
 }
 
 bitflags! {
@@ -166,4 +203,45 @@ mod test {
         test(-1, -16, -1);
         test(-2, -32, -17);
     }
+
+    #[test]
+    fn chunkpos_distances() {
+        let a = ChunkPos::new(1, -2, 3);
+        let b = ChunkPos::new(-1, 2, 4);
+
+        assert_eq!(4, a.distance_chebyshev(b));
+        assert_eq!(4, b.distance_chebyshev(a));
+        assert_eq!(4 + 16 + 1, a.distance_euclidean_sq(b));
+        assert_eq!(0, a.distance_euclidean_sq(a));
+
+        let far = ChunkPos::new(i32::MIN, 0, 0);
+        let far_other = ChunkPos::new(i32::MAX, 0, 0);
+        assert_eq!(u32::MAX, far.distance_chebyshev(far_other));
+        assert_eq!(
+            (u32::MAX as u64).pow(2),
+            far.distance_euclidean_sq(far_other)
+        );
+        assert_eq!(
+            50_000 * 50_000,
+            ChunkPos::new(0, 0, 0).distance_euclidean_sq(ChunkPos::new(0, -50_000, 0))
+        );
+    }
+
+    #[test]
+    fn chunkpos_neighbors() {
+        let pos = ChunkPos::new(4, -2, 7);
+
+        let neighbors = pos.neighbors_iter().collect::<Vec<_>>();
+        assert_eq!(26, neighbors.len());
+        assert!(!neighbors.contains(&pos));
+        assert!(neighbors.contains(&ChunkPos::new(3, -3, 6)));
+        assert!(neighbors.contains(&ChunkPos::new(5, -1, 8)));
+
+        let face_neighbors = pos.face_neighbors_iter().collect::<Vec<_>>();
+        assert_eq!(6, face_neighbors.len());
+        for neighbor in face_neighbors {
+            let offset = neighbor.as_ivec3() - pos.as_ivec3();
+            assert_eq!(1, offset.abs().dot(IVec3::ONE));
+        }
+    }
 }
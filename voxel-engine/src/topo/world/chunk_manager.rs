@@ -7,10 +7,7 @@ use std::{
     time::Duration,
 };
 
-use bevy::{
-    ecs::entity::Entity,
-    math::{ivec3, IVec3},
-};
+use bevy::{ecs::entity::Entity, math::IVec3};
 use dashmap::{mapref::one::Ref, DashSet};
 use parking_lot::{MappedRwLockReadGuard, RwLock, RwLockReadGuard, RwLockWriteGuard};
 
@@ -20,7 +17,7 @@ use crate::{
         controller::LoadReasons,
         neighbors::{Neighbors, NEIGHBOR_ARRAY_SIZE, NEIGHBOR_CUBIC_ARRAY_DIMENSIONS},
     },
-    util::{ivec3_to_1d, ChunkMap, ChunkSet, SyncHashMap},
+    util::{ivec3_to_1d, ChunkMap, ChunkPosHasher, ChunkSet, SyncHashMap},
 };
 
 use super::{
@@ -67,6 +64,15 @@ impl LoadedChunkContainer {
         Ok(LccRef(chunk))
     }
 
+    /// The number of chunks in this container. Blocks if the container is globally locked.
+    pub fn len(&self) -> usize {
+        self.map.read().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Get the state of the global lock for this chunk container
     pub fn global_lock_state(&self) -> GlobalLockState {
         if self.force_write.load(Ordering::Relaxed) || self.map.is_locked_exclusive() {
@@ -109,11 +115,11 @@ impl LoadedChunkContainer {
 }
 
 #[derive(Default)]
-pub struct PendingChunkChanges(DashSet<ChunkPos, ahash::RandomState>);
+pub struct PendingChunkChanges(DashSet<ChunkPos, ChunkPosHasher>);
 
 impl PendingChunkChanges {
     pub fn new() -> Self {
-        Self(DashSet::with_hasher(ahash::RandomState::new()))
+        Self(DashSet::with_hasher(ChunkPosHasher::default()))
     }
 
     pub fn clear(&self) {
@@ -139,9 +145,9 @@ impl PendingChunkChanges {
 
 #[derive(Default)]
 pub struct ChunkStatuses {
-    pub updated: DashSet<ChunkPos, fxhash::FxBuildHasher>,
-    pub generating: DashSet<ChunkPos, fxhash::FxBuildHasher>,
-    pub fresh: DashSet<ChunkPos, fxhash::FxBuildHasher>,
+    pub updated: DashSet<ChunkPos, ChunkPosHasher>,
+    pub generating: DashSet<ChunkPos, ChunkPosHasher>,
+    pub fresh: DashSet<ChunkPos, ChunkPosHasher>,
 }
 
 /// Indicates what happened when we tried to load a chunk
@@ -293,6 +299,11 @@ impl ChunkManager {
             .ok()
     }
 
+    /// The number of loaded chunks, including primordial chunks. Blocks if the chunk manager is globally locked.
+    pub fn num_loaded_chunks(&self) -> usize {
+        self.loaded_chunks.len()
+    }
+
     /// Get the state of the global lock
     pub fn global_lock_state(&self) -> GlobalLockState {
         self.loaded_chunks.global_lock_state()
@@ -343,20 +354,12 @@ impl ChunkManager {
         let mut refs =
             std::array::from_fn::<Option<ChunkRef>, { NEIGHBOR_ARRAY_SIZE }, _>(|_| None);
 
-        for x in -1..=1 {
-            for y in -1..=1 {
-                for z in -1..=1 {
-                    let nbrpos = ivec3(x, y, z);
-                    if nbrpos == IVec3::ZERO {
-                        continue;
-                    }
-
-                    let nbrpos_ws = ChunkPos::from(nbrpos + IVec3::from(pos));
-                    if let Ok(chunk_ref) = self.get_loaded_chunk(nbrpos_ws, false) {
-                        refs[ivec3_to_1d(nbrpos + IVec3::ONE, NEIGHBOR_CUBIC_ARRAY_DIMENSIONS)
-                            .unwrap()] = Some(chunk_ref)
-                    }
-                }
+        for nbrpos_ws in pos.neighbors_iter() {
+            let nbrpos = nbrpos_ws.as_ivec3() - pos.as_ivec3();
+
+            if let Ok(chunk_ref) = self.get_loaded_chunk(nbrpos_ws, false) {
+                refs[ivec3_to_1d(nbrpos + IVec3::ONE, NEIGHBOR_CUBIC_ARRAY_DIMENSIONS).unwrap()] =
+                    Some(chunk_ref)
             }
         }
 
@@ -138,6 +138,20 @@ impl<'a> ChunkRef<'a> {
         }));
         x
     }
+
+    /// Optimistically get read access to this chunk's blocks without blocking. Returns `None` if the
+    /// blocks are currently being written to, in which case the caller should either try again later or
+    /// make do without the data.
+    /// Intended for systems that can tolerate stale data (like distant rendering): the returned access
+    /// can briefly lag behind concurrent writes that are waiting on the lock, and a writer may change the
+    /// blocks as soon as the access is dropped.
+    pub fn try_get_blocks(&self) -> Option<ChunkRefReadAccess<'_, ahash::RandomState>> {
+        let block_variant_access = self.chunk.variants.try_read_access()?;
+
+        Some(ChunkRefReadAccess {
+            block_variants: block_variant_access,
+        })
+    }
 }
 
 pub struct ChunkRefReadAccess<'a, S: BuildHasher = ahash::RandomState> {
@@ -287,3 +301,18 @@ impl<'a, S: BuildHasher> ReadAccess for ChunkRefReadAccess<'a, S> {
 }
 
 impl<'a, S: BuildHasher> ChunkBounds for ChunkRefReadAccess<'a, S> {}
+
+impl<'a, S: BuildHasher> ChunkRefReadAccess<'a, S> {
+    /// Like [`ReadAccess::get`] but without bounds checking.
+    ///
+    /// # Safety
+    /// `pos` must be within the chunk bounds.
+    #[inline]
+    pub unsafe fn get_unchecked(&self, pos: IVec3) -> Result<ChunkAccessOutput<'_>, ChunkAccessError> {
+        // SAFETY: upheld by the caller
+        let block = unsafe { self.block_variants.get_unchecked(pos) }
+            .ok_or(ChunkAccessError::NotInitialized)?;
+
+        Ok(ChunkAccessOutput::new(block))
+    }
+}
@@ -20,7 +20,7 @@ use crate::{
     util::{ivec3_to_1d, SyncHashMap},
 };
 
-use super::{chunk_manager::ChunkManager, ChunkPos};
+use super::{chunk_manager::ChunkManager, ChunkManagerError, ChunkPos, ChunkRef};
 
 #[derive(Resource)]
 pub struct ChunkManagerResource(pub(crate) Arc<ChunkManager>);
@@ -44,6 +44,16 @@ impl<'w> VoxelRealm<'w> {
         &self.permits
     }
 
+    /// Run the given closure with the loaded (non-primordial) chunk at the given position.
+    /// Errors if the chunk couldn't be retrieved from the chunk manager.
+    pub fn with_chunk<T, F>(&self, pos: ChunkPos, f: F) -> Result<T, ChunkManagerError>
+    where
+        F: for<'a> FnOnce(ChunkRef<'a>) -> T,
+    {
+        let cref = self.cm().get_loaded_chunk(pos, false)?;
+        Ok(f(cref))
+    }
+
     pub fn has_render_permit(&self, pos: ChunkPos) -> bool {
         self.permits()
             .get(ChunkPermitKey::Chunk(pos))
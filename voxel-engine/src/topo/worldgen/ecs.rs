@@ -7,7 +7,7 @@ use bevy::{
 
 use crate::{
     data::registries::Registries,
-    topo::{world::VoxelRealm, worldgen::GeneratorPoolSettings},
+    topo::{controller::ChunkGeneratedEvent, world::VoxelRealm, worldgen::GeneratorPoolSettings},
     util::ChunkMap,
 };
 
@@ -76,3 +76,14 @@ pub fn generate_chunks_from_events(
         debug!("Queued {} generation jobs from events", total);
     }
 }
+
+pub fn dispatch_generated_events(
+    workers: Res<GeneratorWorkerPool>,
+    mut events: EventWriter<ChunkGeneratedEvent>,
+) {
+    events.send_batch(
+        workers
+            .generated_chunks()
+            .map(|pos| ChunkGeneratedEvent { pos }),
+    );
+}
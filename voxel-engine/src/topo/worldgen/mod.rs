@@ -24,7 +24,9 @@ use crate::{
 
 use self::generator::Generator;
 
-use super::world::{chunk::ChunkFlags, chunk_manager::GlobalLockState, ChunkManager, ChunkPos};
+use super::world::{
+    chunk::ChunkFlags, chunk_manager::GlobalLockState, ChunkManager, ChunkPos, ChunkRef,
+};
 
 pub mod ecs;
 pub mod error;
@@ -41,6 +43,7 @@ pub struct WorkerParams {
     pub registries: Registries,
     pub chunk_manager: Arc<ChunkManager>,
     pub cmds: Receiver<GeneratorCommand>,
+    pub finished: Sender<ChunkPos>,
     pub timeout: Duration,
 }
 
@@ -79,6 +82,23 @@ impl Keyed<GenerationPriority> for GeneratorCommand {
     }
 }
 
+/// Mark the chunk as generated and report it as finished. The flags are updated before the chunk is reported
+/// so that anyone receiving the position can access the chunk as a regular (non-primordial) chunk.
+fn finish_generation(cref: &ChunkRef, finished: &Sender<ChunkPos>) {
+    // At last we remove both the primordial flag and the generating flag, indicating that
+    // this chunk is ready to be treated as any other chunk.
+    // We also set the remesh flags here so that the mesh is built.
+    cref.update_flags(|flags| {
+        flags.remove(ChunkFlags::GENERATING | ChunkFlags::PRIMORDIAL);
+        flags.insert(
+            ChunkFlags::FRESHLY_GENERATED | ChunkFlags::REMESH_NEIGHBORS | ChunkFlags::REMESH,
+        );
+    });
+
+    // The receiver is only dropped when the worker pool is, in which case nobody cares about the result anyway.
+    let _ = finished.send(cref.pos());
+}
+
 async fn internal_worker_task(
     generator: Generator,
     params: WorkerParams,
@@ -163,15 +183,7 @@ async fn internal_worker_task(
             return;
         }
 
-        // At last we remove both the primordial flag and the generating flag, indicating that
-        // this chunk is ready to be treated as any other chunk.
-        // We also set the remesh flags here so that the mesh is built.
-        cref.update_flags(|flags| {
-            flags.remove(ChunkFlags::GENERATING | ChunkFlags::PRIMORDIAL);
-            flags.insert(
-                ChunkFlags::FRESHLY_GENERATED | ChunkFlags::REMESH_NEIGHBORS | ChunkFlags::REMESH,
-            );
-        });
+        finish_generation(&cref, &params.finished);
     }
 }
 
@@ -213,6 +225,7 @@ pub struct GeneratorPoolSettings {
 pub struct GeneratorWorkerPool {
     workers: Vec<Worker>,
     cmds: Sender<GeneratorCommand>,
+    finished: Receiver<ChunkPos>,
     pending: BinaryHeap<KeyedOrd<GeneratorCommand, GenerationPriority>>,
 }
 
@@ -226,6 +239,7 @@ impl GeneratorWorkerPool {
     ) -> Self {
         let (cmd_sender, cmd_recver) =
             channel::bounded::<GeneratorCommand>(settings.job_channel_capacity);
+        let (finished_sender, finished_recver) = channel::unbounded::<ChunkPos>();
         let mut workers = Vec::<Worker>::with_capacity(settings.workers);
 
         let default_channel_timeout_duration = Duration::from_millis(50);
@@ -234,6 +248,7 @@ impl GeneratorWorkerPool {
             registries,
             chunk_manager: cm,
             cmds: cmd_recver,
+            finished: finished_sender,
             timeout: default_channel_timeout_duration,
         };
 
@@ -251,10 +266,16 @@ impl GeneratorWorkerPool {
         Self {
             workers,
             cmds: cmd_sender,
+            finished: finished_recver,
             pending: BinaryHeap::new(),
         }
     }
 
+    /// Positions of the chunks that the workers finished generating since the last time this was called.
+    pub fn generated_chunks(&self) -> impl Iterator<Item = ChunkPos> + '_ {
+        self.finished.try_iter()
+    }
+
     pub fn shutdown(self) {
         for worker in self.workers.into_iter() {
             block_on(worker.stop());
@@ -283,3 +304,39 @@ impl GeneratorWorkerPool {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data::registries::block::BlockVariantId,
+        topo::{block::FullBlock, controller::LoadReasons, world::chunk_manager::ChunkLoadResult},
+    };
+
+    use super::*;
+
+    #[test]
+    fn generated_chunks_are_not_primordial() {
+        let cm = ChunkManager::new(FullBlock::new(BlockVariantId::new(0)));
+        let pos = ChunkPos::new(1, -2, 3);
+
+        let result = cm
+            .with_global_lock(None, false, |mut access| {
+                access.load_chunk(pos, LoadReasons::MANUAL)
+            })
+            .unwrap()
+            .unwrap();
+
+        // Newly loaded chunks are primordial until they're generated
+        assert_eq!(ChunkLoadResult::New, result);
+        assert!(cm.get_loaded_chunk(pos, false).is_err());
+
+        let (sender, receiver) = channel::unbounded();
+
+        let cref = cm.get_loaded_chunk(pos, true).unwrap();
+        finish_generation(&cref, &sender);
+        drop(cref);
+
+        assert_eq!(vec![pos], receiver.try_iter().collect::<Vec<_>>());
+        assert!(cm.get_loaded_chunk(pos, false).is_ok());
+    }
+}
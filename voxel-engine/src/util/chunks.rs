@@ -1,3 +1,4 @@
+use bevy::math::IVec2;
 use dashmap::{
     mapref::{entry::Entry as DashMapEntry, one::Ref as DashMapRef},
     DashMap,
@@ -8,14 +9,18 @@ use itertools::Itertools;
 
 use crate::topo::world::ChunkPos;
 
+/// The hasher used for all maps and sets keyed by [`ChunkPos`].
+/// This hasher is fast, non-cryptographic, and has a fixed seed, so hashing is deterministic across runs.
+pub type ChunkPosHasher = wyhash2::WyHash;
+
 #[derive(Clone, Default, Debug)]
-pub struct ChunkSet(hb::HashSet<ChunkPos, wyhash2::WyHash>);
+pub struct ChunkSet(hb::HashSet<ChunkPos, ChunkPosHasher>);
 
 impl ChunkSet {
     pub fn with_capacity(capacity: usize) -> Self {
         Self(hb::HashSet::with_capacity_and_hasher(
             capacity,
-            wyhash2::WyHash::default(),
+            ChunkPosHasher::default(),
         ))
     }
 
@@ -42,10 +47,45 @@ impl ChunkSet {
     pub fn clear(&mut self) {
         self.0.clear()
     }
+
+    /// Add all positions in `other` to this set.
+    pub fn union_with(&mut self, other: &ChunkSet) {
+        self.extend(other.iter())
+    }
+
+    /// Only keep the positions in this set that are also in `other`.
+    pub fn intersection_with(&mut self, other: &ChunkSet) {
+        self.0.retain(|pos| other.0.contains(pos))
+    }
+
+    /// Remove all positions in `other` from this set.
+    pub fn difference_with(&mut self, other: &ChunkSet) {
+        if other.len() < self.len() {
+            for pos in other.0.iter() {
+                self.0.remove(pos);
+            }
+        } else {
+            self.0.retain(|pos| !other.0.contains(pos))
+        }
+    }
+}
+
+impl FromIterator<ChunkPos> for ChunkSet {
+    fn from_iter<T: IntoIterator<Item = ChunkPos>>(iter: T) -> Self {
+        let mut set = Self::default();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<ChunkPos> for ChunkSet {
+    fn extend<T: IntoIterator<Item = ChunkPos>>(&mut self, iter: T) {
+        self.0.extend(iter)
+    }
 }
 
 #[derive(Clone)]
-pub struct SyncChunkMap<T>(DashMap<ChunkPos, T, wyhash2::WyHash>);
+pub struct SyncChunkMap<T>(DashMap<ChunkPos, T, ChunkPosHasher>);
 
 impl<T> Default for SyncChunkMap<T> {
     fn default() -> Self {
@@ -55,14 +95,14 @@ impl<T> Default for SyncChunkMap<T> {
 
 impl<T> SyncChunkMap<T> {
     pub fn new() -> Self {
-        Self(DashMap::with_hasher(wyhash2::WyHash::default()))
+        Self(DashMap::with_hasher(ChunkPosHasher::default()))
     }
 
     pub fn set(&self, pos: ChunkPos, data: T) -> Option<T> {
         self.0.insert(pos, data)
     }
 
-    pub fn get(&self, pos: ChunkPos) -> Option<DashMapRef<ChunkPos, T, wyhash2::WyHash>> {
+    pub fn get(&self, pos: ChunkPos) -> Option<DashMapRef<'_, ChunkPos, T, ChunkPosHasher>> {
         self.0.get(&pos)
     }
 
@@ -74,7 +114,7 @@ impl<T> SyncChunkMap<T> {
         self.0.contains_key(&pos)
     }
 
-    pub fn entry(&self, pos: ChunkPos) -> DashMapEntry<'_, ChunkPos, T, wyhash2::WyHash> {
+    pub fn entry(&self, pos: ChunkPos) -> DashMapEntry<'_, ChunkPos, T, ChunkPosHasher> {
         self.0.entry(pos)
     }
 
@@ -104,8 +144,78 @@ impl<T> SyncChunkMap<T> {
     }
 }
 
+/// Concurrent map for 2D data overlaid on the voxel world (biomes, surface heights, foliage density, etc.).
+/// Keys are the XZ coordinates of chunks.
 #[derive(Clone)]
-pub struct ChunkMap<T>(hb::HashMap<ChunkPos, T, wyhash2::WyHash>);
+pub struct VoxelMap2D<T>(DashMap<IVec2, T, FxBuildHasher>);
+
+impl<T> Default for VoxelMap2D<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> VoxelMap2D<T> {
+    pub fn new() -> Self {
+        Self(DashMap::with_hasher(FxBuildHasher::default()))
+    }
+
+    pub fn set(&self, chunk_xz: IVec2, data: T) -> Option<T> {
+        self.0.insert(chunk_xz, data)
+    }
+
+    pub fn get(&self, chunk_xz: IVec2) -> Option<DashMapRef<'_, IVec2, T, FxBuildHasher>> {
+        self.0.get(&chunk_xz)
+    }
+
+    pub fn remove(&self, chunk_xz: IVec2) -> Option<T> {
+        self.0.remove(&chunk_xz).map(|(_, data)| data)
+    }
+
+    pub fn contains(&self, chunk_xz: IVec2) -> bool {
+        self.0.contains_key(&chunk_xz)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Run `f` for every entry within the rectangle between `min` and `max` (inclusive).
+    pub fn for_each_in_rect<F>(&self, min: IVec2, max: IVec2, mut f: F)
+    where
+        F: FnMut(IVec2, &T),
+    {
+        let (min, max) = (min.min(max), min.max(max));
+        let area = ((max - min) + IVec2::ONE).as_i64vec2();
+
+        // If the rectangle is smaller than the map we look up every position in the rectangle,
+        // otherwise it's faster to just go through the entire map.
+        if (area.x * area.y) < self.0.len() as i64 {
+            for x in min.x..=max.x {
+                for z in min.y..=max.y {
+                    let pos = IVec2::new(x, z);
+                    if let Some(entry) = self.0.get(&pos) {
+                        f(pos, entry.value());
+                    }
+                }
+            }
+        } else {
+            for entry in self.0.iter() {
+                let pos = *entry.key();
+                if pos.cmpge(min).all() && pos.cmple(max).all() {
+                    f(pos, entry.value());
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ChunkMap<T>(hb::HashMap<ChunkPos, T, ChunkPosHasher>);
 
 impl<T> Default for ChunkMap<T> {
     fn default() -> Self {
@@ -115,13 +225,13 @@ impl<T> Default for ChunkMap<T> {
 
 impl<T> ChunkMap<T> {
     pub fn new() -> Self {
-        Self(hb::HashMap::with_hasher(wyhash2::WyHash::default()))
+        Self(hb::HashMap::with_hasher(ChunkPosHasher::default()))
     }
 
     pub fn with_capacity(capacity: usize) -> Self {
         Self(hb::HashMap::with_capacity_and_hasher(
             capacity,
-            wyhash2::WyHash::default(),
+            ChunkPosHasher::default(),
         ))
     }
 
@@ -145,7 +255,7 @@ impl<T> ChunkMap<T> {
         self.0.contains_key(&pos)
     }
 
-    pub fn entry(&mut self, pos: ChunkPos) -> HashbrownEntry<'_, ChunkPos, T, wyhash2::WyHash> {
+    pub fn entry(&mut self, pos: ChunkPos) -> HashbrownEntry<'_, ChunkPos, T, ChunkPosHasher> {
         self.0.entry(pos)
     }
 
@@ -204,3 +314,69 @@ impl<T> ChunkMap<T> {
         self.0.drain()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::ivec2;
+
+    use super::*;
+
+    fn chunk_set(positions: &[[i32; 3]]) -> ChunkSet {
+        positions
+            .iter()
+            .map(|&[x, y, z]| ChunkPos::new(x, y, z))
+            .collect()
+    }
+
+    #[test]
+    fn chunk_set_batch_operations() {
+        let a = chunk_set(&[[0, 0, 0], [1, 0, 0], [2, 0, 0]]);
+        let b = chunk_set(&[[1, 0, 0], [2, 0, 0], [3, 0, 0]]);
+
+        let mut union = a.clone();
+        union.union_with(&b);
+        assert_eq!(4, union.len());
+        assert!(union.contains(ChunkPos::new(0, 0, 0)));
+        assert!(union.contains(ChunkPos::new(3, 0, 0)));
+
+        let mut intersection = a.clone();
+        intersection.intersection_with(&b);
+        assert_eq!(2, intersection.len());
+        assert!(!intersection.contains(ChunkPos::new(0, 0, 0)));
+        assert!(intersection.contains(ChunkPos::new(1, 0, 0)));
+
+        let mut difference = a.clone();
+        difference.difference_with(&b);
+        assert_eq!(1, difference.len());
+        assert!(difference.contains(ChunkPos::new(0, 0, 0)));
+    }
+
+    #[test]
+    fn voxel_map_2d_rect_query() {
+        let map = VoxelMap2D::<i32>::new();
+
+        for x in -4..4 {
+            for z in -4..4 {
+                map.set(ivec2(x, z), x * z);
+            }
+        }
+
+        let mut small = Vec::new();
+        map.for_each_in_rect(ivec2(1, 1), ivec2(0, 0), |pos, &v| small.push((pos, v)));
+        small.sort_by_key(|(pos, _)| pos.to_array());
+
+        assert_eq!(
+            vec![
+                (ivec2(0, 0), 0),
+                (ivec2(0, 1), 0),
+                (ivec2(1, 0), 0),
+                (ivec2(1, 1), 1)
+            ],
+            small
+        );
+
+        let mut large = 0;
+        map.for_each_in_rect(ivec2(-100, -100), ivec2(100, 100), |_, _| large += 1);
+        assert_eq!(64, large);
+    }
+}